@@ -0,0 +1,77 @@
+-- Gazetteer: one row per named feature (OBJNAM/NOBJNM) across all layers
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE TABLE IF NOT EXISTS place_names (
+    id SERIAL PRIMARY KEY,
+    enc_name TEXT NOT NULL,
+    layer TEXT NOT NULL,
+    object_class TEXT NOT NULL,
+    feature_fid INTEGER NOT NULL,
+    objnam TEXT,
+    nobjnm TEXT,
+    compilation_scale INTEGER NOT NULL,
+    point GEOMETRY(POINT, 4326) NOT NULL,
+    bbox GEOMETRY(GEOMETRY, 4326) NOT NULL,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        to_tsvector('simple', COALESCE(objnam, '') || ' ' || COALESCE(nobjnm, ''))
+    ) STORED,
+    CONSTRAINT place_names_unique_feature UNIQUE (enc_name, layer, feature_fid)
+);
+
+CREATE INDEX IF NOT EXISTS place_names_search_idx ON place_names USING GIN(search_vector);
+CREATE INDEX IF NOT EXISTS place_names_objnam_trgm_idx ON place_names USING GIN(objnam gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS place_names_nobjnm_trgm_idx ON place_names USING GIN(nobjnm gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS place_names_point_idx ON place_names USING GIST(point);
+CREATE INDEX IF NOT EXISTS place_names_enc_name_idx ON place_names(enc_name);
+
+-- Ranked name search combining full-text and trigram similarity.
+-- The same feature charted in several cells is returned once, from the best-scale cell.
+CREATE OR REPLACE FUNCTION enc_place_search(query text, max_results integer DEFAULT 20)
+RETURNS json
+AS $$
+    WITH q AS (
+        SELECT websearch_to_tsquery('simple', query) AS tsq
+    ),
+    matches AS (
+        SELECT
+            p.*,
+            ts_rank(p.search_vector, q.tsq)
+                + GREATEST(
+                    similarity(COALESCE(p.objnam, ''), query),
+                    similarity(COALESCE(p.nobjnm, ''), query)
+                ) AS rank
+        FROM place_names p, q
+        WHERE
+            p.search_vector @@ q.tsq
+            OR p.objnam % query
+            OR p.nobjnm % query
+    ),
+    best AS (
+        -- Collapse duplicates of one feature across cells (same layer, name and ~1 km cell)
+        SELECT DISTINCT ON (m.layer, LOWER(COALESCE(m.objnam, m.nobjnm)), ST_SnapToGrid(m.point, 0.01))
+            m.*
+        FROM matches m
+        ORDER BY
+            m.layer,
+            LOWER(COALESCE(m.objnam, m.nobjnm)),
+            ST_SnapToGrid(m.point, 0.01),
+            m.compilation_scale ASC
+    )
+    SELECT COALESCE(json_agg(r ORDER BY r.rank DESC, r.compilation_scale ASC), '[]'::json)
+    FROM (
+        SELECT
+            b.objnam AS name,
+            b.nobjnm AS national_name,
+            b.layer,
+            b.object_class,
+            b.enc_name,
+            b.compilation_scale,
+            ST_X(b.point) AS lon,
+            ST_Y(b.point) AS lat,
+            json_build_array(ST_XMin(b.bbox), ST_YMin(b.bbox), ST_XMax(b.bbox), ST_YMax(b.bbox)) AS bbox,
+            b.rank
+        FROM best b
+        ORDER BY b.rank DESC, b.compilation_scale ASC
+        LIMIT max_results
+    ) AS r
+$$ LANGUAGE sql STABLE PARALLEL SAFE;
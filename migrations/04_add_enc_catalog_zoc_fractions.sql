@@ -0,0 +1,2 @@
+-- Share of each cell's M_QUAL area per zone of confidence, e.g. {"A1": 0.25, "C": 0.75}
+ALTER TABLE enc_catalog ADD COLUMN IF NOT EXISTS zoc_fractions JSONB;
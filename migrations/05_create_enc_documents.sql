@@ -0,0 +1,27 @@
+-- Text and picture files referenced by TXTDSC/NTXTDS/PICREP, one row per cell and file
+CREATE TABLE IF NOT EXISTS enc_documents (
+    id SERIAL PRIMARY KEY,
+    enc_name TEXT NOT NULL,
+    filename TEXT NOT NULL,
+    media_type TEXT NOT NULL,
+    content_text TEXT,
+    content_bytes BYTEA,
+    CONSTRAINT enc_documents_unique_file UNIQUE (enc_name, filename)
+);
+
+CREATE INDEX IF NOT EXISTS enc_documents_enc_name_idx ON enc_documents(enc_name);
+
+-- One document as JSON; pictures are returned base64-encoded
+CREATE OR REPLACE FUNCTION enc_document(cell text, document_filename text)
+RETURNS json
+AS $$
+    SELECT json_build_object(
+        'enc_name', d.enc_name,
+        'filename', d.filename,
+        'media_type', d.media_type,
+        'text', d.content_text,
+        'data', encode(d.content_bytes, 'base64')
+    )
+    FROM enc_documents d
+    WHERE d.enc_name = cell AND d.filename = document_filename
+$$ LANGUAGE sql STABLE PARALLEL SAFE;
@@ -0,0 +1,2 @@
+-- Navigational purpose of each cell: 1 overview, 2 general, 3 coastal, 4 approach, 5 harbour, 6 berthing
+ALTER TABLE enc_catalog ADD COLUMN IF NOT EXISTS usage_band INTEGER;
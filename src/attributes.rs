@@ -0,0 +1,826 @@
+//! S-57 attribute dictionary
+//!
+//! Maps attribute acronyms to their S-57 names and, for enumerated attributes,
+//! code values to their meanings (S-57 Appendix A, Chapter 2). Used to decode
+//! stored values into human-readable pick reports.
+
+use serde_json::{Map, Value, json};
+
+/// One S-57 attribute: acronym, full name and enumerated values (empty for
+/// free-text and numeric attributes)
+pub struct AttributeDef {
+    pub acronym: &'static str,
+    pub name: &'static str,
+    pub values: &'static [(i64, &'static str)],
+}
+
+impl AttributeDef {
+    pub const fn new(
+        acronym: &'static str,
+        name: &'static str,
+        values: &'static [(i64, &'static str)],
+    ) -> Self {
+        Self {
+            acronym,
+            name,
+            values,
+        }
+    }
+}
+
+pub const ATTRIBUTES: &[AttributeDef] = &[
+    AttributeDef::new(
+        "BOYSHP",
+        "Buoy shape",
+        &[
+            (1, "conical (nun, ogival)"),
+            (2, "can (cylindrical)"),
+            (3, "spherical"),
+            (4, "pillar"),
+            (5, "spar (spindle)"),
+            (6, "barrel (tun)"),
+            (7, "super-buoy"),
+            (8, "ice buoy"),
+        ],
+    ),
+    AttributeDef::new("BURDEP", "Buried depth", &[]),
+    AttributeDef::new(
+        "CATACH",
+        "Category of anchorage",
+        &[
+            (1, "unrestricted anchorage"),
+            (2, "deep water anchorage"),
+            (3, "tanker anchorage"),
+            (4, "explosives anchorage"),
+            (5, "quarantine anchorage"),
+            (6, "sea-plane anchorage"),
+            (7, "small craft anchorage"),
+            (8, "small craft mooring area"),
+            (9, "anchorage for periods up to 24 hours"),
+            (10, "anchorage for a limited period of time"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATBRG",
+        "Category of bridge",
+        &[
+            (1, "fixed bridge"),
+            (2, "opening bridge"),
+            (3, "swing bridge"),
+            (4, "lifting bridge"),
+            (5, "bascule bridge"),
+            (6, "pontoon bridge"),
+            (7, "draw bridge"),
+            (8, "transporter bridge"),
+            (9, "footbridge"),
+            (10, "viaduct"),
+            (11, "aqueduct"),
+            (12, "suspension bridge"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATCBL",
+        "Category of cable",
+        &[
+            (1, "power line"),
+            (3, "transmission line"),
+            (4, "telephone"),
+            (5, "telegraph"),
+            (6, "mooring cable/chain"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATCOA",
+        "Category of coastline",
+        &[
+            (1, "steep coast"),
+            (2, "flat coast"),
+            (3, "sandy shore"),
+            (4, "stony shore"),
+            (5, "shingly shore"),
+            (6, "glacier (seaward end)"),
+            (7, "mangrove"),
+            (8, "marshy shore"),
+            (9, "coral reef"),
+            (10, "ice coast"),
+            (11, "shelly shore"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATHAF",
+        "Category of harbour facility",
+        &[
+            (1, "RoRo-terminal"),
+            (3, "ferry terminal"),
+            (4, "fishing harbour"),
+            (5, "yacht harbour/marina"),
+            (6, "naval base"),
+            (7, "tanker terminal"),
+            (8, "passenger terminal"),
+            (9, "shipyard"),
+            (10, "container terminal"),
+            (11, "bulk terminal"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATHLK",
+        "Category of hulk",
+        &[
+            (1, "floating restaurant"),
+            (2, "historic ship"),
+            (3, "museum"),
+            (4, "accommodation"),
+            (5, "floating breakwater"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATLIT",
+        "Category of light",
+        &[
+            (1, "directional function"),
+            (2, "rear/upper light"),
+            (3, "front/lower light"),
+            (4, "leading light"),
+            (5, "aero light"),
+            (6, "air obstruction light"),
+            (7, "fog detector light"),
+            (8, "flood light"),
+            (9, "strip light"),
+            (10, "subsidiary light"),
+            (11, "spotlight"),
+            (12, "front"),
+            (13, "rear"),
+            (14, "lower"),
+            (15, "upper"),
+            (16, "moiré effect"),
+            (17, "emergency"),
+            (18, "bearing light"),
+            (19, "horizontally disposed"),
+            (20, "vertically disposed"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATLMK",
+        "Category of landmark",
+        &[
+            (1, "cairn"),
+            (2, "cemetery"),
+            (3, "chimney"),
+            (4, "dish aerial"),
+            (5, "flagstaff (flagpole)"),
+            (6, "flare stack"),
+            (7, "mast"),
+            (8, "windsock"),
+            (9, "monument"),
+            (10, "column (pillar)"),
+            (11, "memorial plaque"),
+            (12, "obelisk"),
+            (13, "statue"),
+            (14, "cross"),
+            (15, "dome"),
+            (16, "radar scanner"),
+            (17, "tower"),
+            (18, "windmill"),
+            (19, "windmotor"),
+            (20, "spire/minaret"),
+            (21, "boulder"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATLND",
+        "Category of land region",
+        &[
+            (1, "fen"),
+            (2, "marsh"),
+            (3, "moor/bog"),
+            (4, "heathland"),
+            (5, "mountain range"),
+            (6, "lowlands"),
+            (7, "canyon lands"),
+            (8, "paddy field"),
+            (9, "agricultural land"),
+            (10, "savanna/grassland"),
+            (11, "parkland"),
+            (12, "swamp"),
+            (13, "landslide"),
+            (14, "lava flow"),
+            (15, "salt pan"),
+            (16, "moraine"),
+            (17, "crater"),
+            (18, "cave"),
+            (19, "rock column or pinnacle"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATMOR",
+        "Category of mooring/warping facility",
+        &[
+            (1, "dolphin"),
+            (2, "deviation dolphin"),
+            (3, "bollard"),
+            (4, "tie-up wall"),
+            (5, "post or pile"),
+            (6, "chain/wire/cable"),
+            (7, "mooring buoy"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATMPA",
+        "Category of military practice area",
+        &[
+            (2, "torpedo exercise area"),
+            (3, "submarine exercise area"),
+            (4, "firing danger area"),
+            (5, "mine-laying practice area"),
+            (6, "small arms firing range"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATNAV",
+        "Category of navigation line",
+        &[
+            (1, "clearing line"),
+            (2, "transit line"),
+            (3, "leading line bearing a recommended track"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATOBS",
+        "Category of obstruction",
+        &[
+            (1, "snag/stump"),
+            (2, "wellhead"),
+            (3, "diffuser"),
+            (4, "crib"),
+            (5, "fish haven"),
+            (6, "foul area"),
+            (7, "foul ground"),
+            (8, "ice boom"),
+            (9, "ground tackle"),
+            (10, "boom"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATPIP",
+        "Category of pipeline/pipe",
+        &[
+            (1, "pipeline in general"),
+            (2, "outfall pipe"),
+            (3, "intake pipe"),
+            (4, "sewer"),
+            (5, "bubbler system"),
+            (6, "supply pipe"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATREA",
+        "Category of restricted area",
+        &[
+            (1, "offshore safety zone"),
+            (4, "nature reserve"),
+            (5, "bird sanctuary"),
+            (6, "game reserve"),
+            (7, "seal sanctuary"),
+            (8, "degaussing range"),
+            (9, "military area"),
+            (10, "historic wreck area"),
+            (12, "navigational aid safety zone"),
+            (14, "minefield"),
+            (18, "swimming area"),
+            (19, "waiting area"),
+            (20, "research area"),
+            (21, "dredging area"),
+            (22, "fish sanctuary"),
+            (23, "ecological reserve"),
+            (24, "no wake area"),
+            (25, "swinging area"),
+            (26, "water skiing area"),
+            (27, "Environmentally Sensitive Sea Area (ESSA)"),
+            (28, "Particularly Sensitive Sea Area (PSSA)"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATSCF",
+        "Category of small craft facility",
+        &[
+            (1, "visitor's berth"),
+            (2, "nautical club"),
+            (3, "boat hoist"),
+            (4, "sailmaker"),
+            (5, "boatyard"),
+            (6, "public inn"),
+            (7, "restaurant"),
+            (8, "chandler"),
+            (9, "provisions"),
+            (10, "doctor"),
+            (11, "pharmacy"),
+            (12, "water tap"),
+            (13, "fuel station"),
+            (14, "electricity"),
+            (15, "bottle gas"),
+            (16, "showers"),
+            (17, "launderette"),
+            (18, "public toilets"),
+            (19, "post box"),
+            (20, "public telephone"),
+            (21, "refuse bin"),
+            (22, "car park"),
+            (23, "parking for boats and trailers"),
+            (24, "caravan site"),
+            (25, "camping site"),
+            (26, "sewerage pump-out station"),
+            (27, "emergency telephone"),
+            (28, "landing/launching place for boats"),
+            (29, "visitors mooring"),
+            (30, "scrubbing berth"),
+            (31, "picnic area"),
+            (32, "mechanics workshop"),
+            (33, "guard and/or security service"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATSIL",
+        "Category of silo/tank",
+        &[
+            (1, "silo in general"),
+            (2, "tank in general"),
+            (3, "grain elevator"),
+            (4, "water tower"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATSLC",
+        "Category of shoreline construction",
+        &[
+            (1, "breakwater"),
+            (2, "groyne (groin)"),
+            (3, "mole"),
+            (4, "pier (jetty)"),
+            (5, "promenade pier"),
+            (6, "wharf (quay)"),
+            (7, "training wall"),
+            (8, "rip rap"),
+            (9, "revetment"),
+            (10, "sea wall"),
+            (11, "landing steps"),
+            (12, "ramp"),
+            (13, "slipway"),
+            (14, "fender"),
+            (15, "solid face wharf"),
+            (16, "open face wharf"),
+            (17, "log ramp"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATTRK",
+        "Category of recommended track",
+        &[
+            (1, "based on a system of fixed marks"),
+            (2, "not based on a system of fixed marks"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATTSS",
+        "Category of Traffic Separation Scheme",
+        &[(1, "IMO - adopted"), (2, "not IMO - adopted")],
+    ),
+    AttributeDef::new(
+        "CATVEG",
+        "Category of vegetation",
+        &[
+            (1, "grass"),
+            (2, "paddy"),
+            (3, "bush"),
+            (4, "deciduous wood"),
+            (5, "coniferous wood"),
+            (6, "wood in general (incl. mixed wood)"),
+            (7, "mangroves"),
+            (8, "park"),
+            (9, "parkland"),
+            (10, "mixed crops"),
+            (11, "reed"),
+            (12, "moss"),
+            (13, "tree in general"),
+            (14, "evergreen tree"),
+            (15, "coniferous tree"),
+            (16, "palm tree"),
+            (17, "nipa palm tree"),
+            (18, "casuarina tree"),
+            (19, "eucalypt tree"),
+            (20, "deciduous tree"),
+            (21, "mangrove tree"),
+            (22, "filao tree"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATWED",
+        "Category of weed/kelp",
+        &[
+            (1, "kelp"),
+            (2, "sea weed"),
+            (3, "sea grass"),
+            (4, "sargasso"),
+        ],
+    ),
+    AttributeDef::new(
+        "CATZOC",
+        "Category of zone of confidence in data",
+        &[
+            (1, "zone of confidence A1"),
+            (2, "zone of confidence A2"),
+            (3, "zone of confidence B"),
+            (4, "zone of confidence C"),
+            (5, "zone of confidence D"),
+            (6, "zone of confidence U (data not assessed)"),
+        ],
+    ),
+    AttributeDef::new(
+        "CAT_TS",
+        "Category of Tidal stream",
+        &[
+            (1, "flood stream"),
+            (2, "ebb stream"),
+            (3, "other tidal flow"),
+        ],
+    ),
+    AttributeDef::new(
+        "COLOUR",
+        "Colour",
+        &[
+            (1, "white"),
+            (2, "black"),
+            (3, "red"),
+            (4, "green"),
+            (5, "blue"),
+            (6, "yellow"),
+            (7, "grey"),
+            (8, "brown"),
+            (9, "amber"),
+            (10, "violet"),
+            (11, "orange"),
+            (12, "magenta"),
+            (13, "pink"),
+        ],
+    ),
+    AttributeDef::new(
+        "CONDTN",
+        "Condition",
+        &[
+            (1, "under construction"),
+            (2, "ruined"),
+            (3, "under reclamation"),
+            (4, "wingless"),
+            (5, "planned construction"),
+        ],
+    ),
+    AttributeDef::new(
+        "CONVIS",
+        "Conspicuous, visually",
+        &[(1, "visually conspicuous"), (2, "not visually conspicuous")],
+    ),
+    AttributeDef::new("CURVEL", "Current velocity", &[]),
+    AttributeDef::new("DEPTH", "Depth", &[]),
+    AttributeDef::new("DRVAL1", "Depth range value 1", &[]),
+    AttributeDef::new("DRVAL2", "Depth range value 2", &[]),
+    AttributeDef::new(
+        "FUNCTN",
+        "Function",
+        &[
+            (1, "no function/service of major interest"),
+            (2, "harbour-master's office"),
+            (3, "custom office"),
+            (4, "health office"),
+            (5, "hospital"),
+            (6, "post office"),
+            (7, "hotel"),
+            (8, "railway station"),
+            (9, "police station"),
+            (10, "water-police station"),
+            (11, "pilot office"),
+            (12, "pilot lookout"),
+            (13, "bank office"),
+            (14, "headquarters for district control"),
+            (15, "transit shed (warehouse)"),
+            (16, "factory"),
+            (17, "power station"),
+            (18, "administrative"),
+            (19, "educational facility"),
+            (20, "church"),
+            (21, "chapel"),
+            (22, "temple"),
+            (23, "pagoda"),
+            (24, "shinto shrine"),
+            (25, "buddhist temple"),
+            (26, "mosque"),
+            (27, "marabout"),
+            (28, "lookout"),
+            (29, "communication"),
+            (30, "television"),
+            (31, "radio"),
+            (32, "radar"),
+            (33, "light support"),
+            (34, "microwave"),
+            (35, "cooling"),
+            (36, "observation"),
+            (37, "timeball"),
+            (38, "clock"),
+            (39, "control"),
+            (40, "airship mooring"),
+            (41, "stadium"),
+            (42, "bus station"),
+        ],
+    ),
+    AttributeDef::new("HEIGHT", "Height", &[]),
+    AttributeDef::new("HORCLR", "Horizontal clearance", &[]),
+    AttributeDef::new(
+        "LITCHR",
+        "Light characteristic",
+        &[
+            (1, "fixed"),
+            (2, "flashing"),
+            (3, "long-flashing"),
+            (4, "quick-flashing"),
+            (5, "very quick-flashing"),
+            (6, "ultra quick-flashing"),
+            (7, "isophased"),
+            (8, "occulting"),
+            (9, "interrupted quick-flashing"),
+            (10, "interrupted very quick-flashing"),
+            (11, "interrupted ultra quick-flashing"),
+            (12, "morse"),
+            (13, "fixed/flash"),
+            (14, "flash/long-flash"),
+            (15, "occulting/flash"),
+            (16, "fixed/long-flash"),
+            (17, "occulting alternating"),
+            (18, "long-flash alternating"),
+            (19, "flash alternating"),
+            (20, "group alternating"),
+            (25, "quick-flash plus long-flash"),
+            (26, "very quick-flash plus long-flash"),
+            (27, "ultra quick-flash plus long-flash"),
+            (28, "alternating"),
+            (29, "fixed and alternating flashing"),
+        ],
+    ),
+    AttributeDef::new(
+        "NATQUA",
+        "Nature of surface - qualifying terms",
+        &[
+            (1, "fine"),
+            (2, "medium"),
+            (3, "coarse"),
+            (4, "broken"),
+            (5, "sticky"),
+            (6, "soft"),
+            (7, "stiff"),
+            (8, "volcanic"),
+            (9, "calcareous"),
+            (10, "hard"),
+        ],
+    ),
+    AttributeDef::new(
+        "NATSUR",
+        "Nature of surface",
+        &[
+            (1, "mud"),
+            (2, "clay"),
+            (3, "silt"),
+            (4, "sand"),
+            (5, "stone"),
+            (6, "gravel"),
+            (7, "pebbles"),
+            (8, "cobbles"),
+            (9, "rock"),
+            (11, "lava"),
+            (14, "coral"),
+            (17, "shells"),
+            (18, "boulder"),
+        ],
+    ),
+    AttributeDef::new("NTXTDS", "Textual description in national language", &[]),
+    AttributeDef::new("OBJNAM", "Object name", &[]),
+    AttributeDef::new("ORIENT", "Orientation", &[]),
+    AttributeDef::new("PICREP", "Pictorial representation", &[]),
+    AttributeDef::new("POSACC", "Positional accuracy", &[]),
+    AttributeDef::new(
+        "PRODCT",
+        "Product",
+        &[
+            (1, "oil"),
+            (2, "gas"),
+            (3, "water"),
+            (4, "stone"),
+            (5, "coal"),
+            (6, "ore"),
+            (7, "chemicals"),
+            (8, "drinking water"),
+            (9, "milk"),
+            (10, "bauxite"),
+            (11, "coke"),
+            (12, "iron ingots"),
+            (13, "salt"),
+            (14, "sand"),
+            (15, "timber"),
+            (16, "sawdust/wood chips"),
+            (17, "scrap metal"),
+            (18, "liquified natural gas (LNG)"),
+            (19, "liquified petroleum gas (LPG)"),
+            (20, "wine"),
+            (21, "cement"),
+            (22, "grain"),
+        ],
+    ),
+    AttributeDef::new(
+        "QUASOU",
+        "Quality of sounding measurement",
+        &[
+            (1, "depth known"),
+            (2, "depth unknown"),
+            (3, "doubtful sounding"),
+            (4, "unreliable sounding"),
+            (5, "no bottom found at value shown"),
+            (6, "least depth known"),
+            (7, "least depth unknown, safe clearance at value shown"),
+            (8, "value reported (not surveyed)"),
+            (9, "value reported (not confirmed)"),
+            (10, "maintained depth"),
+            (11, "not regularly maintained"),
+        ],
+    ),
+    AttributeDef::new("RADIUS", "Radius", &[]),
+    AttributeDef::new(
+        "RESTRN",
+        "Restriction",
+        &[
+            (1, "anchoring prohibited"),
+            (2, "anchoring restricted"),
+            (3, "fishing prohibited"),
+            (4, "fishing restricted"),
+            (5, "trawling prohibited"),
+            (6, "trawling restricted"),
+            (7, "entry prohibited"),
+            (8, "entry restricted"),
+            (9, "dredging prohibited"),
+            (10, "dredging restricted"),
+            (11, "diving prohibited"),
+            (12, "diving restricted"),
+            (13, "no wake"),
+            (14, "area to be avoided"),
+            (15, "construction prohibited"),
+            (16, "discharging prohibited"),
+            (17, "discharging restricted"),
+            (
+                18,
+                "industrial or mineral exploration/development prohibited",
+            ),
+            (
+                19,
+                "industrial or mineral exploration/development restricted",
+            ),
+            (20, "drilling prohibited"),
+            (21, "drilling restricted"),
+            (22, "removal of historical artifacts prohibited"),
+            (23, "cargo transhipment (lightering) prohibited"),
+            (24, "dragging prohibited"),
+            (25, "stopping prohibited"),
+            (26, "landing prohibited"),
+            (27, "speed restricted"),
+        ],
+    ),
+    AttributeDef::new("RYRMGV", "Reference year for magnetic variation", &[]),
+    AttributeDef::new("SCAMIN", "Scale minimum", &[]),
+    AttributeDef::new("SIGPER", "Signal period", &[]),
+    AttributeDef::new("SORDAT", "Source date", &[]),
+    AttributeDef::new("SORIND", "Source indication", &[]),
+    AttributeDef::new(
+        "STATUS",
+        "Status",
+        &[
+            (1, "permanent"),
+            (2, "occasional"),
+            (3, "recommended"),
+            (4, "not in use"),
+            (5, "periodic/intermittent"),
+            (6, "reserved"),
+            (7, "temporary"),
+            (8, "private"),
+            (9, "mandatory"),
+            (11, "extinguished"),
+            (12, "illuminated"),
+            (13, "historic"),
+            (14, "public"),
+            (15, "synchronized"),
+            (16, "watched"),
+            (17, "un-watched"),
+            (18, "existence doubtful"),
+        ],
+    ),
+    AttributeDef::new("SUREND", "Survey date - end", &[]),
+    AttributeDef::new("SURSTA", "Survey date - start", &[]),
+    AttributeDef::new(
+        "TECSOU",
+        "Technique of sounding measurement",
+        &[
+            (1, "found by echo-sounder"),
+            (2, "found by side scan sonar"),
+            (3, "found by multi-beam"),
+            (4, "found by diver"),
+            (5, "found by lead-line"),
+            (6, "swept by wire-drag"),
+            (7, "found by laser"),
+            (8, "swept by vertical acoustic system"),
+            (9, "found by electromagnetic sensor"),
+            (10, "photogrammetry"),
+            (11, "satellite imagery"),
+            (12, "found by levelling"),
+            (13, "swept by side-scan sonar"),
+            (14, "computer generated"),
+        ],
+    ),
+    AttributeDef::new("TIMEND", "Time end", &[]),
+    AttributeDef::new("TIMSTR", "Time start", &[]),
+    AttributeDef::new(
+        "TRAFIC",
+        "Traffic flow",
+        &[
+            (1, "inbound"),
+            (2, "outbound"),
+            (3, "one-way"),
+            (4, "two-way"),
+        ],
+    ),
+    AttributeDef::new("TS_TSP", "Tidal stream, panel values", &[]),
+    AttributeDef::new("TS_TSV", "Tidal stream, time series values", &[]),
+    AttributeDef::new("TXTDSC", "Textual description", &[]),
+    AttributeDef::new(
+        "T_ACWL",
+        "Tide, accuracy of water level",
+        &[
+            (1, "better than 0.1 m and 10 minutes"),
+            (2, "worse than 0.1 m or 10 minutes"),
+        ],
+    ),
+    AttributeDef::new("T_HWLW", "Tide, high and low water values", &[]),
+    AttributeDef::new(
+        "T_MTOD",
+        "Tide, method of tidal prediction",
+        &[
+            (1, "simplified harmonic method of tidal prediction"),
+            (2, "full harmonic method of tidal prediction"),
+            (3, "height and time difference non-harmonic method"),
+        ],
+    ),
+    AttributeDef::new("T_THDF", "Tide, time and height differences", &[]),
+    AttributeDef::new("T_TINT", "Tide, time interval of values", &[]),
+    AttributeDef::new("T_TSVL", "Tide, time series values", &[]),
+    AttributeDef::new("T_VAHC", "Tide, value of harmonic constituents", &[]),
+    AttributeDef::new(
+        "VALACM",
+        "Value of annual change in magnetic variation",
+        &[],
+    ),
+    AttributeDef::new("VALLMA", "Value of local magnetic anomaly", &[]),
+    AttributeDef::new("VALMAG", "Value of magnetic variation", &[]),
+    AttributeDef::new("VALNMR", "Value of nominal range", &[]),
+    AttributeDef::new("VALSOU", "Value of sounding", &[]),
+    AttributeDef::new("VERCCL", "Vertical clearance, closed", &[]),
+    AttributeDef::new("VERCLR", "Vertical clearance", &[]),
+    AttributeDef::new("VERCOP", "Vertical clearance, open", &[]),
+    AttributeDef::new("VERCSA", "Vertical clearance, safe", &[]),
+    AttributeDef::new("VERLEN", "Vertical length", &[]),
+    AttributeDef::new(
+        "WATLEV",
+        "Water level effect",
+        &[
+            (1, "partly submerged at high water"),
+            (2, "always dry"),
+            (3, "always under water/submerged"),
+            (4, "covers and uncovers"),
+            (5, "awash"),
+            (6, "subject to inundation or flooding"),
+            (7, "floating"),
+        ],
+    ),
+];
+
+/// Build the attribute dictionary as JSON:
+/// `{"CATLIT": {"name": "Category of light", "values": {"1": "directional function", ...}}, ...}`
+pub fn dictionary_json() -> Value {
+    let mut dict = Map::new();
+    for attr in ATTRIBUTES {
+        let values: Map<String, Value> = attr
+            .values
+            .iter()
+            .map(|(code, meaning)| (code.to_string(), json!(meaning)))
+            .collect();
+        dict.insert(
+            attr.acronym.to_string(),
+            json!({
+                "name": attr.name,
+                "values": values,
+            }),
+        );
+    }
+    Value::Object(dict)
+}
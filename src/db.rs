@@ -2,6 +2,7 @@ use log::info;
 use sqlx::{PgPool, postgres::PgPoolOptions};
 use std::time::Duration;
 
+use crate::documents::EncDocument;
 use crate::feature::LayerDef;
 use crate::s57::S57Metadata;
 
@@ -28,7 +29,8 @@ pub async fn run_migrations(pool: &PgPool) {
 
 /// Create tables, indexes, and MVT functions for all registered layers.
 /// Uses idempotent DDL so it's a no-op for existing tables.
-pub async fn ensure_layer_tables(pool: &PgPool) {
+/// `overscale_factor` is the default for the overscale layer of `enc_mvt`.
+pub async fn ensure_layer_tables(pool: &PgPool, overscale_factor: f64) {
     let layers = crate::features::all_layers();
     for def in layers {
         sqlx::query(&def.create_table_sql())
@@ -36,6 +38,13 @@ pub async fn ensure_layer_tables(pool: &PgPool) {
             .await
             .unwrap_or_else(|e| panic!("Failed to create table {}: {}", def.table, e));
 
+        for sql in def.add_missing_columns_sql() {
+            sqlx::query(&sql)
+                .execute(pool)
+                .await
+                .unwrap_or_else(|e| panic!("Failed to add columns to {}: {}", def.table, e));
+        }
+
         for sql in def.create_indexes_sql() {
             sqlx::query(&sql)
                 .execute(pool)
@@ -52,25 +61,52 @@ pub async fn ensure_layer_tables(pool: &PgPool) {
     }
 
     // Create unified MVT function that combines all layers
-    let unified_mvt_sql = create_unified_mvt_function_sql(&layers);
+    let unified_mvt_sql = create_unified_mvt_function_sql(&layers, overscale_factor);
     sqlx::query(&unified_mvt_sql)
         .execute(pool)
         .await
         .expect("Failed to create unified MVT function");
 
     info!("Created unified enc_mvt function");
+
+    // Create pick-report functions that decode attributes for all layers
+    sqlx::query(&create_decode_attributes_function_sql())
+        .execute(pool)
+        .await
+        .expect("Failed to create enc_decode_attributes function");
+
+    sqlx::query(&create_feature_info_function_sql(layers))
+        .execute(pool)
+        .await
+        .expect("Failed to create enc_feature_info function");
+
+    info!("Created enc_feature_info function");
+
+    sqlx::query(&create_route_check_function_sql())
+        .execute(pool)
+        .await
+        .expect("Failed to create enc_route_check function");
+
+    info!("Created enc_route_check function");
 }
 
 /// Generate a unified MVT function that combines all feature layers into a single source
-fn create_unified_mvt_function_sql(layers: &[&LayerDef]) -> String {
-    let layer_mvts: Vec<String> = layers
+fn create_unified_mvt_function_sql(layers: &[&LayerDef], overscale_factor: f64) -> String {
+    let mut layer_mvts: Vec<String> = layers
         .iter()
         .map(|def| {
             // Build layer-specific column list
             let layer_select_cols: String = def
                 .columns
                 .iter()
-                .map(|c| format!(",\n                d.{}", c.sql_column))
+                .map(|c| format!(",\n                {}", c.mvt_select()))
+                .collect();
+
+            // Derived properties (rotation, clearance labels, restriction flags)
+            let derived: String = def
+                .derived_properties_sql()
+                .iter()
+                .map(|p| format!(",\n                {}", p))
                 .collect();
 
             // Special handling for soundg: add depth unit conversions
@@ -102,6 +138,11 @@ fn create_unified_mvt_function_sql(layers: &[&LayerDef]) -> String {
                 d.ac AS "AC",
                 d.lc AS "LC",
                 d.sy AS "SY",
+                d.ap AS "AP",
+                d.ls AS "LS",
+                d.display_category AS "DISPCAT",
+                d.viewing_group AS "VIEWGRP",
+                d.display_priority AS "PRIO"{derived},
                 d.scamin,
                 d.sordat,
                 d.attributes{depth_conv}
@@ -116,11 +157,15 @@ fn create_unified_mvt_function_sql(layers: &[&LayerDef]) -> String {
         WHERE geom IS NOT NULL), ''::bytea)"#,
                 table = def.table,
                 layer_cols = layer_select_cols,
+                derived = derived,
                 depth_conv = depth_conversions,
             )
         })
         .collect();
 
+    layer_mvts.push(catalog_mvt_sql());
+    layer_mvts.push(overscale_mvt_sql());
+
     let mvt_concatenation = layer_mvts.join("\n    || ");
 
     format!(
@@ -131,22 +176,364 @@ DECLARE
     mvt bytea;
     tile_env geometry;
     tile_env_4326 geometry;
+    overscale_factor double precision;
 BEGIN
     tile_env := ST_TileEnvelope(z, x, y);
     tile_env_4326 := ST_Transform(tile_env, 4326);
+    -- A malformed or non-positive ?overscale_factor= falls back to the default
+    -- instead of failing the whole tile
+    overscale_factor := {overscale_factor};
+    IF (query_params->>'overscale_factor') ~ '^\s*([0-9]{{1,6}}(\.[0-9]*)?|\.[0-9]+)\s*$' THEN
+        IF (query_params->>'overscale_factor')::double precision > 0 THEN
+            overscale_factor := (query_params->>'overscale_factor')::double precision;
+        END IF;
+    END IF;
 
     SELECT INTO mvt
-    {}
+    {mvt_concatenation}
     ;
 
     RETURN mvt;
 END;
 $$ LANGUAGE plpgsql STABLE PARALLEL SAFE;"#,
-        mvt_concatenation
+        overscale_factor = overscale_factor,
+        mvt_concatenation = mvt_concatenation,
+    )
+}
+
+/// `enc_catalog` source layer: the coverage polygon of every cell touching
+/// the tile, so a client can outline cells and tell which one it is showing.
+/// Placeholder coverage points (cells still waiting for the convex hull
+/// fallback) are left out.
+fn catalog_mvt_sql() -> String {
+    r#"COALESCE((SELECT ST_AsMVT(tile, 'enc_catalog', 4096, 'geom')
+        FROM (
+            SELECT
+                ST_AsMVTGeom(
+                    ST_Transform(c.coverage, 3857),
+                    tile_env,
+                    4096,
+                    128,
+                    true
+                ) AS geom,
+                c.enc_name,
+                c.edition,
+                c.update_number,
+                c.compilation_scale,
+                c.usage_band
+            FROM enc_catalog c
+            WHERE
+                c.coverage && tile_env_4326
+                AND ST_Dimension(c.coverage) = 2
+            ORDER BY c.compilation_scale DESC
+        ) AS tile
+        WHERE geom IS NOT NULL), ''::bytea)"#
+        .to_string()
+}
+
+/// `overscale` source layer: the part of each cell's coverage shown at
+/// `overscale_factor` or more times its compilation scale, tagged with the
+/// S-52 OVERSC01 pattern. The overscale is `2 ^ (z - zoom)`, with the cell's
+/// zoom from the same rule as `min_zoom`. Areas also covered by a cell that
+/// is drawn but not overscaled at this zoom are cut out, since that cell's
+/// data is drawn on top there.
+fn overscale_mvt_sql() -> String {
+    r#"COALESCE((SELECT ST_AsMVT(tile, 'overscale', 4096, 'geom')
+        FROM (
+            SELECT
+                ST_AsMVTGeom(
+                    ST_Transform(o.area, 3857),
+                    tile_env,
+                    4096,
+                    128,
+                    true
+                ) AS geom,
+                o.enc_name,
+                o.compilation_scale,
+                o.overscale::double precision AS overscale,
+                'OVERSC01P'::text AS "AP"
+            FROM (
+                SELECT
+                    c.enc_name,
+                    c.compilation_scale,
+                    c.overscale,
+                    COALESCE(
+                        ST_Difference(
+                            ST_Intersection(c.coverage, tile_env_4326),
+                            (SELECT ST_Union(b.coverage)
+                             FROM enc_catalog b
+                             WHERE b.coverage && tile_env_4326
+                               AND ST_Dimension(b.coverage) = 2
+                               AND (28 - CEIL(LN(GREATEST(b.compilation_scale, 1)) / LN(2))) <= z
+                               AND 2 ^ (z - (28 - CEIL(LN(GREATEST(b.compilation_scale, 1)) / LN(2)))) < overscale_factor)
+                        ),
+                        ST_Intersection(c.coverage, tile_env_4326)
+                    ) AS area
+                FROM (
+                    SELECT
+                        cc.enc_name,
+                        cc.compilation_scale,
+                        cc.coverage,
+                        2 ^ (z - (28 - CEIL(LN(GREATEST(cc.compilation_scale, 1)) / LN(2)))) AS overscale
+                    FROM enc_catalog cc
+                    WHERE
+                        cc.coverage && tile_env_4326
+                        AND ST_Dimension(cc.coverage) = 2
+                ) c
+                WHERE c.overscale >= overscale_factor
+            ) o
+            WHERE NOT ST_IsEmpty(o.area)
+        ) AS tile
+        WHERE geom IS NOT NULL), ''::bytea)"#
+        .to_string()
+}
+
+/// Generate `enc_decode_attributes(attrs jsonb)`, which turns an `{"ACRONYM": value}`
+/// object into a list of `{acronym, name, value, meaning}` entries using the
+/// S-57 attribute dictionary embedded at generation time.
+fn create_decode_attributes_function_sql() -> String {
+    let dictionary = crate::attributes::dictionary_json()
+        .to_string()
+        .replace('\'', "''");
+
+    format!(
+        r#"CREATE OR REPLACE FUNCTION enc_decode_attributes(attrs jsonb)
+RETURNS jsonb
+AS $$
+    SELECT COALESCE(jsonb_agg(jsonb_build_object(
+        'acronym', a.key,
+        'name', COALESCE(dict -> a.key ->> 'name', a.key),
+        'value', a.value,
+        'meaning', CASE
+            WHEN jsonb_typeof(a.value) = 'array' THEN
+                (SELECT jsonb_agg(dict -> a.key -> 'values' -> (e #>> '{{}}'))
+                 FROM jsonb_array_elements(a.value) AS e)
+            ELSE dict -> a.key -> 'values' -> (a.value #>> '{{}}')
+        END
+    ) ORDER BY a.key), '[]'::jsonb)
+    FROM jsonb_each(COALESCE(attrs, '{{}}'::jsonb)) AS a,
+        (SELECT '{dictionary}'::jsonb AS dict) AS d
+    WHERE a.value <> 'null'::jsonb
+$$ LANGUAGE sql IMMUTABLE PARALLEL SAFE;"#,
+        dictionary = dictionary,
     )
 }
 
+/// Generate `enc_feature_info(lon, lat, z, radius_px)`, a pick report returning every
+/// feature within `radius_px` screen pixels of the point, drawn from the best-scale
+/// cell that is visible at zoom `z`, with decoded attribute names and values.
+fn create_feature_info_function_sql(layers: &[&LayerDef]) -> String {
+    let layer_selects: Vec<String> = layers
+        .iter()
+        .map(|def| {
+            // Layer-specific columns keyed by their S-57 acronym
+            let attr_pairs: String = def
+                .columns
+                .iter()
+                .map(|c| format!("'{}', d.{}, ", c.s57_field, c.sql_column))
+                .collect();
+
+            format!(
+                r#"SELECT
+            '{table}' AS layer,
+            '{s57_name}' AS object_class,
+            d.id,
+            d.enc_name,
+            d.compilation_scale,
+            ST_GeometryType(d.geom) AS geometry_type,
+            ST_Distance(d.geom_3857, pt_3857) / tolerance * radius_px AS distance_px,
+            enc_decode_attributes(
+                jsonb_build_object({attr_pairs}'SCAMIN', d.scamin, 'SORDAT', d.sordat, 'SORIND', d.sorind)
+                || COALESCE(d.attributes, '{{}}'::jsonb)
+            ) AS attributes,
+            (SELECT json_agg(json_build_object(
+                    'attribute', r.key,
+                    'filename', doc.filename,
+                    'media_type', doc.media_type,
+                    'text', doc.content_text
+                ) ORDER BY r.key)
+             FROM jsonb_each_text(d.attributes) AS r
+             JOIN enc_documents doc ON doc.enc_name = d.enc_name AND doc.filename = r.value
+             WHERE r.key IN ('TXTDSC', 'NTXTDS', 'PICREP')) AS documents
+        FROM {table} d
+        WHERE
+            d.enc_name = cell
+            AND ST_DWithin(d.geom_3857, pt_3857, tolerance)
+            AND d.min_zoom <= z
+            AND (d.max_zoom IS NULL OR d.max_zoom <= z)"#,
+                table = def.table,
+                s57_name = def.s57_name,
+                attr_pairs = attr_pairs,
+            )
+        })
+        .collect();
+
+    let union = layer_selects.join("\n        UNION ALL\n        ");
+
+    format!(
+        r#"CREATE OR REPLACE FUNCTION enc_feature_info(lon double precision, lat double precision, z integer, radius_px double precision DEFAULT 8)
+RETURNS json
+AS $$
+DECLARE
+    result json;
+    pt geometry;
+    pt_3857 geometry;
+    tolerance double precision;
+    cell text;
+BEGIN
+    pt := ST_SetSRID(ST_MakePoint(lon, lat), 4326);
+    pt_3857 := ST_Transform(pt, 3857);
+
+    -- Web Mercator units per 256px tile pixel at this zoom
+    tolerance := radius_px * 40075016.68557849 / (256 * 2 ^ z);
+
+    -- Best-scale cell covering the point whose features are visible at this zoom
+    SELECT c.enc_name INTO cell
+    FROM enc_catalog c
+    WHERE
+        ST_Intersects(c.coverage, pt)
+        AND (28 - CEIL(LN(GREATEST(c.compilation_scale, 1)) / LN(2))) <= z
+    ORDER BY c.compilation_scale ASC
+    LIMIT 1;
+
+    IF cell IS NULL THEN
+        RETURN '[]'::json;
+    END IF;
+
+    SELECT INTO result COALESCE(json_agg(f ORDER BY f.distance_px), '[]'::json)
+    FROM (
+        {union}
+    ) AS f;
+
+    RETURN result;
+END;
+$$ LANGUAGE plpgsql STABLE PARALLEL SAFE;"#,
+        union = union,
+    )
+}
+
+/// Generate `enc_route_check(route_geojson, draft, safety_margin, corridor_m)`, which
+/// returns every hazard within a corridor around each leg of a route: depth areas
+/// with DRVAL1 and soundings shallower than draft + safety margin, and land areas.
+/// Each part of the corridor is checked against the best-scale cell covering it.
+fn create_route_check_function_sql() -> String {
+    use crate::features::{DEPARE, LNDARE, SOUNDG};
+
+    format!(
+        r#"CREATE OR REPLACE FUNCTION enc_route_check(
+    route_geojson text,
+    draft double precision,
+    safety_margin double precision DEFAULT 0,
+    corridor_m double precision DEFAULT 185.2
+)
+RETURNS json
+AS $$
+    WITH route AS (
+        SELECT ST_Force2D(ST_SetSRID(ST_GeomFromGeoJSON(route_geojson), 4326)) AS geom
+    ),
+    -- Each LineString of the route (a MultiLineString has several), so the
+    -- legs below never join the end of one part to the start of the next
+    parts AS (
+        SELECT d.path, d.geom
+        FROM route r, ST_Dump(r.geom) AS d
+        WHERE ST_GeometryType(d.geom) = 'ST_LineString'
+    ),
+    legs AS (
+        SELECT
+            (ROW_NUMBER() OVER (ORDER BY p.path, n))::integer AS leg,
+            ST_MakeLine(ST_PointN(p.geom, n), ST_PointN(p.geom, n + 1)) AS leg_geom
+        FROM parts p, generate_series(1, ST_NPoints(p.geom) - 1) AS n
+    ),
+    corridors AS (
+        SELECT leg, leg_geom, ST_Buffer(leg_geom::geography, corridor_m)::geometry AS corridor
+        FROM legs
+    ),
+    -- Part of each leg's corridor where a cell is the best-scale chart available
+    leg_cells AS (
+        SELECT
+            k.leg,
+            c.enc_name,
+            ST_Difference(
+                ST_Intersection(c.coverage, k.corridor),
+                COALESCE(
+                    (SELECT ST_Union(b.coverage)
+                     FROM enc_catalog b
+                     WHERE b.compilation_scale < c.compilation_scale
+                       AND ST_Dimension(b.coverage) = 2
+                       AND ST_Intersects(b.coverage, k.corridor)),
+                    ST_GeomFromText('POLYGON EMPTY', 4326)
+                )
+            ) AS area
+        FROM corridors k
+        JOIN enc_catalog c
+            ON ST_Dimension(c.coverage) = 2
+            AND ST_Intersects(c.coverage, k.corridor)
+    ),
+    hazards AS (
+        SELECT lc.leg, '{depare}' AS layer, d.id, d.enc_name, d.compilation_scale, d.geom,
+            'depth area shallower than safety depth' AS reason, d.drval1::double precision AS depth, NULL::text AS objnam
+        FROM {depare} d
+        JOIN leg_cells lc ON d.enc_name = lc.enc_name AND ST_Intersects(d.geom, lc.area)
+        WHERE d.drval1 < draft + safety_margin
+        UNION ALL
+        SELECT lc.leg, '{soundg}' AS layer, d.id, d.enc_name, d.compilation_scale, d.geom,
+            'sounding shallower than safety depth' AS reason, d.depth::double precision AS depth, NULL::text AS objnam
+        FROM {soundg} d
+        JOIN leg_cells lc ON d.enc_name = lc.enc_name AND ST_Intersects(d.geom, lc.area)
+        WHERE d.depth < draft + safety_margin
+        UNION ALL
+        SELECT lc.leg, '{lndare}' AS layer, d.id, d.enc_name, d.compilation_scale, d.geom,
+            'land within corridor' AS reason, NULL::double precision AS depth, d.objnam
+        FROM {lndare} d
+        JOIN leg_cells lc ON d.enc_name = lc.enc_name AND ST_Intersects(d.geom, lc.area)
+    )
+    SELECT COALESCE(json_agg(r ORDER BY r.leg, r.leg_fraction), '[]'::json)
+    FROM (
+        SELECT
+            h.leg,
+            h.layer,
+            h.id,
+            h.enc_name,
+            h.compilation_scale,
+            h.reason,
+            h.depth,
+            h.objnam,
+            draft + safety_margin AS safety_depth,
+            ST_X(ST_ClosestPoint(h.geom, k.leg_geom)) AS lon,
+            ST_Y(ST_ClosestPoint(h.geom, k.leg_geom)) AS lat,
+            ST_Distance(h.geom::geography, k.leg_geom::geography) AS distance_m,
+            ST_LineLocatePoint(k.leg_geom, ST_ClosestPoint(k.leg_geom, h.geom)) AS leg_fraction
+        FROM hazards h
+        JOIN corridors k ON k.leg = h.leg
+    ) AS r
+$$ LANGUAGE sql STABLE PARALLEL SAFE;"#,
+        depare = DEPARE.table,
+        soundg = SOUNDG.table,
+        lndare = LNDARE.table,
+    )
+}
+
+/// Run `enc_route_check` for a route given as a GeoJSON LineString or MultiLineString
+pub async fn check_route(
+    pool: &PgPool,
+    route_geojson: &str,
+    draft: f64,
+    safety_margin: f64,
+    corridor_m: f64,
+) -> Result<serde_json::Value, sqlx::Error> {
+    let (result,): (sqlx::types::Json<serde_json::Value>,) =
+        sqlx::query_as("SELECT enc_route_check($1, $2, $3, $4)")
+            .bind(route_geojson)
+            .bind(draft)
+            .bind(safety_margin)
+            .bind(corridor_m)
+            .fetch_one(pool)
+            .await?;
+    Ok(result.0)
+}
+
 /// Insert or update enc_catalog row for a chart cell.
+/// The usage band comes from DSID_INTU, or the cell name when that is missing.
 /// If coverage_geojson is None, inserts a placeholder point at 0,0 that will
 /// be updated later with a convex hull fallback.
 pub async fn upsert_enc_catalog(
@@ -155,16 +542,21 @@ pub async fn upsert_enc_catalog(
     metadata: &S57Metadata,
     coverage_geojson: Option<&str>,
 ) -> Result<(), sqlx::Error> {
+    let usage_band = metadata
+        .usage_band
+        .or_else(|| crate::util::usage_band_from_enc_name(enc_name));
+
     match coverage_geojson {
         Some(geojson) => {
             sqlx::query(
                 r#"
-                INSERT INTO enc_catalog (enc_name, compilation_scale, edition, update_number, coverage)
-                VALUES ($1, $2, $3, $4, ST_SetSRID(ST_GeomFromGeoJSON($5), 4326))
+                INSERT INTO enc_catalog (enc_name, compilation_scale, edition, update_number, usage_band, coverage)
+                VALUES ($1, $2, $3, $4, $5, ST_SetSRID(ST_GeomFromGeoJSON($6), 4326))
                 ON CONFLICT (enc_name) DO UPDATE SET
                     compilation_scale = EXCLUDED.compilation_scale,
                     edition = EXCLUDED.edition,
                     update_number = EXCLUDED.update_number,
+                    usage_band = EXCLUDED.usage_band,
                     coverage = EXCLUDED.coverage
                 "#,
             )
@@ -172,6 +564,7 @@ pub async fn upsert_enc_catalog(
             .bind(metadata.compilation_scale)
             .bind(metadata.edition)
             .bind(metadata.update_number)
+            .bind(usage_band)
             .bind(geojson)
             .execute(&mut **tx)
             .await?;
@@ -180,18 +573,20 @@ pub async fn upsert_enc_catalog(
             // Insert with a dummy point; will be replaced by convex hull fallback
             sqlx::query(
                 r#"
-                INSERT INTO enc_catalog (enc_name, compilation_scale, edition, update_number, coverage)
-                VALUES ($1, $2, $3, $4, ST_SetSRID(ST_MakePoint(0, 0), 4326))
+                INSERT INTO enc_catalog (enc_name, compilation_scale, edition, update_number, usage_band, coverage)
+                VALUES ($1, $2, $3, $4, $5, ST_SetSRID(ST_MakePoint(0, 0), 4326))
                 ON CONFLICT (enc_name) DO UPDATE SET
                     compilation_scale = EXCLUDED.compilation_scale,
                     edition = EXCLUDED.edition,
-                    update_number = EXCLUDED.update_number
+                    update_number = EXCLUDED.update_number,
+                    usage_band = EXCLUDED.usage_band
                 "#,
             )
             .bind(enc_name)
             .bind(metadata.compilation_scale)
             .bind(metadata.edition)
             .bind(metadata.update_number)
+            .bind(usage_band)
             .execute(&mut **tx)
             .await?;
         }
@@ -199,6 +594,98 @@ pub async fn upsert_enc_catalog(
     Ok(())
 }
 
+/// Remove a cell's gazetteer entries before reimporting it, so names dropped
+/// by a new edition or update don't linger.
+pub async fn clear_place_names(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    enc_name: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM place_names WHERE enc_name = $1")
+        .bind(enc_name)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// Remove a cell's referenced documents before reimporting it
+pub async fn clear_enc_documents(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    enc_name: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM enc_documents WHERE enc_name = $1")
+        .bind(enc_name)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// Insert or update a text or picture file referenced by the cell's features
+pub async fn upsert_enc_document(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    enc_name: &str,
+    document: &EncDocument,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO enc_documents (enc_name, filename, media_type, content_text, content_bytes)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (enc_name, filename) DO UPDATE SET
+            media_type = EXCLUDED.media_type,
+            content_text = EXCLUDED.content_text,
+            content_bytes = EXCLUDED.content_bytes
+        "#,
+    )
+    .bind(enc_name)
+    .bind(&document.filename)
+    .bind(document.media_type)
+    .bind(document.text.as_deref())
+    .bind(document.data.as_deref())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Store the share of a cell's M_QUAL area in each zone of confidence (CATZOC)
+/// in `enc_catalog.zoc_fractions`, e.g. `{"A1": 0.25, "C": 0.75}`. Cells
+/// without M_QUAL areas get NULL.
+pub async fn update_catalog_zoc_fractions(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    enc_name: &str,
+) -> Result<(), sqlx::Error> {
+    use crate::features::M_QUAL;
+
+    let sql = format!(
+        r#"
+        WITH zones AS (
+            SELECT
+                CASE q.catzoc
+                    WHEN 1 THEN 'A1' WHEN 2 THEN 'A2' WHEN 3 THEN 'B'
+                    WHEN 4 THEN 'C' WHEN 5 THEN 'D' ELSE 'U'
+                END AS zoc,
+                SUM(ST_Area(q.geom::geography)) AS area
+            FROM {m_qual} q
+            WHERE q.enc_name = $1 AND ST_Dimension(q.geom) = 2
+            GROUP BY 1
+        ),
+        fractions AS (
+            SELECT zoc, area / NULLIF(SUM(area) OVER (), 0) AS fraction
+            FROM zones
+        )
+        UPDATE enc_catalog
+        SET zoc_fractions = (
+            SELECT jsonb_object_agg(zoc, ROUND(fraction::numeric, 4))
+            FROM fractions
+            WHERE fraction IS NOT NULL
+        )
+        WHERE enc_name = $1
+        "#,
+        m_qual = M_QUAL.table,
+    );
+
+    sqlx::query(&sql).bind(enc_name).execute(&mut **tx).await?;
+    Ok(())
+}
+
 /// Update enc_catalog coverage from convex hull of all features when M_COVR was missing
 pub async fn update_catalog_coverage_fallback(
     pool: &PgPool,
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::s57::S57Metadata;
+
+/// External text or picture file referenced by a feature
+pub struct EncDocument {
+    pub filename: String,
+    pub media_type: &'static str,
+    /// Decoded content of text files (TXTDSC, NTXTDS)
+    pub text: Option<String>,
+    /// Raw content of picture files (PICREP)
+    pub data: Option<Vec<u8>>,
+}
+
+/// Find `filename` in the cell directory. Exchange sets are often written on
+/// case-insensitive file systems, so names are matched ignoring case.
+fn find_file(cell_dir: &Path, filename: &str) -> Option<PathBuf> {
+    let exact = cell_dir.join(filename);
+    if exact.is_file() {
+        return Some(exact);
+    }
+
+    fs::read_dir(cell_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.eq_ignore_ascii_case(filename))
+        })
+}
+
+/// Decode text at an S-57 lexical level: 0 (ASCII) and 1 (ISO 8859-1) map each
+/// byte to one character, 2 is UCS-2, little-endian unless a byte order mark
+/// says otherwise. NUL padding is dropped, since Postgres text cannot hold it.
+fn decode_text(bytes: &[u8], lexical_level: i32) -> String {
+    if lexical_level < 2 {
+        return bytes
+            .iter()
+            .filter(|&&b| b != 0)
+            .map(|&b| char::from(b))
+            .collect();
+    }
+
+    let (big_endian, body) = match bytes {
+        [0xFE, 0xFF, rest @ ..] => (true, rest),
+        [0xFF, 0xFE, rest @ ..] => (false, rest),
+        _ => (false, bytes),
+    };
+    let units = body.chunks_exact(2).map(|pair| {
+        if big_endian {
+            u16::from_be_bytes([pair[0], pair[1]])
+        } else {
+            u16::from_le_bytes([pair[0], pair[1]])
+        }
+    });
+
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .filter(|&c| c != '\0')
+        .collect()
+}
+
+/// Media type for a picture file, from its extension
+fn picture_media_type(filename: &str) -> &'static str {
+    let ext = Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    match ext.as_str() {
+        "tif" | "tiff" => "image/tiff",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Read the file a feature references through `attribute` (TXTDSC, NTXTDS or
+/// PICREP) from the cell directory. Returns None when the file is missing.
+pub fn load_document(
+    cell_dir: &Path,
+    attribute: &str,
+    filename: &str,
+    metadata: &S57Metadata,
+) -> Option<Result<EncDocument, std::io::Error>> {
+    let path = find_file(cell_dir, filename)?;
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) => return Some(Err(e)),
+    };
+
+    let document = match attribute {
+        "PICREP" => EncDocument {
+            filename: filename.to_string(),
+            media_type: picture_media_type(filename),
+            text: None,
+            data: Some(bytes),
+        },
+        _ => {
+            // TXTDSC is ATTF text, NTXTDS is NATF text
+            let lexical_level = if attribute == "NTXTDS" {
+                metadata.natf_lexical_level
+            } else {
+                metadata.attf_lexical_level
+            };
+            EncDocument {
+                filename: filename.to_string(),
+                media_type: "text/plain",
+                text: Some(decode_text(&bytes, lexical_level)),
+                data: None,
+            }
+        }
+    };
+
+    Some(Ok(document))
+}
@@ -1,6 +1,6 @@
 use gdal::vector::Feature;
 use serde_json::{Map, Value};
-use sqlx::{Postgres, Transaction};
+use sqlx::{Acquire, Postgres, Transaction};
 
 use crate::s57::S57Metadata;
 use crate::style::StyleLayerDef;
@@ -12,6 +12,8 @@ pub struct CommonAttributes {
     pub objl: Option<i32>,
     pub sordat: Option<String>,
     pub sorind: Option<String>,
+    pub objnam: Option<String>,
+    pub nobjnm: Option<String>,
     pub other_attributes: Map<String, Value>,
 }
 
@@ -26,7 +28,11 @@ pub struct ChartContext<'a> {
 pub enum ColType {
     Float,
     Int,
+    /// S-57 list attribute (e.g. RESTRN), stored as all of its values
+    IntList,
     Text,
+    /// Structured value parsed from an S-57 string attribute (e.g. a time series)
+    Json(fn(&Value) -> Option<Value>),
 }
 
 impl ColType {
@@ -34,7 +40,9 @@ impl ColType {
         match self {
             ColType::Float => "NUMERIC",
             ColType::Int => "INTEGER",
+            ColType::IntList => "INTEGER[]",
             ColType::Text => "TEXT",
+            ColType::Json(_) => "JSONB",
         }
     }
 }
@@ -54,14 +62,116 @@ impl ColumnDef {
             col_type,
         }
     }
+
+    /// Select expression for tile properties. JSONB values are sent as text,
+    /// since ST_AsMVT would otherwise expand objects into separate properties.
+    pub fn mvt_select(&self) -> String {
+        match self.col_type {
+            ColType::Json(_) => format!("d.{0}::text AS {0}", self.sql_column),
+            _ => format!("d.{}", self.sql_column),
+        }
+    }
+}
+
+/// S-52 display category, from most to least essential
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayCategory {
+    /// Display Base: always shown, cannot be removed
+    Base,
+    /// Standard display, the default ECDIS selection
+    Standard,
+    /// All other information, shown on demand
+    Other,
+}
+
+impl DisplayCategory {
+    pub const ALL: &[DisplayCategory] = &[Self::Base, Self::Standard, Self::Other];
+
+    /// Value stored in the `display_category` column and the DISPCAT tile property
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Standard => "standard",
+            Self::Other => "other",
+        }
+    }
 }
 
 /// Style properties computed during import
 #[derive(Debug, Default, Clone)]
 pub struct StyleProps {
-    pub ac: Option<String>, // area color token
-    pub lc: Option<String>, // line color token
-    pub sy: Option<String>, // point symbol name
+    pub ac: Option<String>,                        // area color token
+    pub lc: Option<String>,                        // line color token
+    pub sy: Option<String>,                        // point symbol name
+    pub ap: Option<String>,                        // area pattern name
+    pub ls: Option<String>,                        // line style name (see style::LINE_STYLES)
+    pub display_category: Option<DisplayCategory>, // overrides LayerDef::display_category
+    pub viewing_group: Option<i32>,                // overrides LayerDef::viewing_group
+    pub display_priority: Option<i32>,             // overrides LayerDef::display_priority
+}
+
+/// Attributes naming external text (TXTDSC, NTXTDS) and picture (PICREP) files.
+/// They are kept in the `attributes` JSONB column of every layer.
+pub const DOCUMENT_ATTRIBUTES: &[&str] = &["TXTDSC", "NTXTDS", "PICREP"];
+
+/// Clearance attributes and their S-52 label prefixes, in label order
+const CLEARANCE_LABELS: &[(&str, &str)] = &[
+    ("VERCLR", "clr"),
+    ("VERCSA", "sf clr"),
+    ("VERCCL", "clr cl"),
+    ("VERCOP", "clr op"),
+    ("HORCLR", "hor clr"),
+];
+
+/// NATQUA codes and their chart abbreviations (INT 1, section J)
+const NATQUA_ABBREVIATIONS: &[(usize, &str)] = &[
+    (1, "f"),
+    (2, "m"),
+    (3, "c"),
+    (4, "bk"),
+    (5, "sy"),
+    (6, "so"),
+    (7, "sf"),
+    (8, "v"),
+    (9, "ca"),
+    (10, "h"),
+];
+
+/// NATSUR codes and their chart abbreviations (INT 1, section J)
+const NATSUR_ABBREVIATIONS: &[(usize, &str)] = &[
+    (1, "M"),
+    (2, "Cy"),
+    (3, "Si"),
+    (4, "S"),
+    (5, "St"),
+    (6, "G"),
+    (7, "P"),
+    (8, "Cb"),
+    (9, "R"),
+    (11, "Lv"),
+    (14, "Co"),
+    (17, "Sh"),
+    (18, "Bo"),
+];
+
+/// SQL `text[]` literal indexed by code, with NULL for codes without an entry
+fn sql_code_lookup(entries: &[(usize, &str)]) -> String {
+    let len = entries.iter().map(|&(code, _)| code).max().unwrap_or(0);
+    let items: Vec<String> = (1..=len)
+        .map(|code| match entries.iter().find(|&&(c, _)| c == code) {
+            Some((_, abbr)) => format!("'{}'", abbr),
+            None => "NULL".to_string(),
+        })
+        .collect();
+    format!("(ARRAY[{}]::text[])", items.join(", "))
+}
+
+/// SQL text for a signed angle in degrees as degrees and minutes east or west, e.g. 4°15'E
+fn sql_degrees_minutes(expr: &str) -> String {
+    format!(
+        "(ROUND(ABS({0}) * 60)::integer / 60) || '°' || to_char(ROUND(ABS({0}) * 60)::integer % 60, 'FM00') || '''' || CASE WHEN {0} < 0 THEN 'W' ELSE 'E' END",
+        expr
+    )
 }
 
 /// Declarative layer definition — all you need to add a new S-57 feature layer
@@ -69,11 +179,133 @@ pub struct LayerDef {
     pub s57_name: &'static str,
     pub table: &'static str,
     pub columns: &'static [ColumnDef],
+    /// Default S-52 display category for the layer's features
+    pub display_category: DisplayCategory,
+    /// Default S-52 viewing group, e.g. 13030 for depth areas
+    pub viewing_group: i32,
+    /// Default S-52 display priority, 0 (no-data areas) to 9 (drawn last)
+    pub display_priority: i32,
     pub style_fn: Option<fn(&Map<String, Value>) -> StyleProps>,
     pub style_layers: &'static [StyleLayerDef],
 }
 
 impl LayerDef {
+    /// Tile properties derived from stored columns, as `expr AS name` select items:
+    /// - `ROT`: ORIENT in degrees clockwise from true north, for icon rotation
+    /// - `bearing_label`: ORIENT formatted as a bearing, e.g. "045.0°"
+    /// - `clearance_label`: formatted clearances, e.g. "clr 25m sf clr 23m"
+    /// - `seabed_label`: NATQUA/NATSUR lists as chart abbreviations, e.g. "fS.M"
+    /// - `magvar_projected`/`magvar_label`: variation projected to the request date
+    ///   (`query_params` "date", default today), e.g. "4°15'E 2025 (7'W)"
+    /// - `curvel_label`: current velocity in knots, e.g. "1.5 kn"
+    /// - `anchoring_prohibited`: RESTRN includes anchoring prohibited (1)
+    pub fn derived_properties_sql(&self) -> Vec<String> {
+        let column = |s57_field: &str| {
+            self.columns
+                .iter()
+                .find(|c| c.s57_field == s57_field)
+                .map(|c| c.sql_column)
+        };
+
+        let list_column = |s57_field: &str| {
+            self.columns
+                .iter()
+                .find(|c| c.s57_field == s57_field && matches!(c.col_type, ColType::IntList))
+                .map(|c| c.sql_column)
+        };
+
+        let mut props = Vec::new();
+
+        if let Some(col) = column("ORIENT") {
+            props.push(format!("d.{}::double precision AS \"ROT\"", col));
+            props.push(format!(
+                "to_char(d.{}, 'FM000.0') || '°' AS bearing_label",
+                col
+            ));
+        }
+
+        let clearances: Vec<String> = CLEARANCE_LABELS
+            .iter()
+            .filter_map(|&(field, prefix)| {
+                column(field)
+                    .map(|col| format!("'{} ' || trim_scale(ROUND(d.{}, 1)) || 'm'", prefix, col))
+            })
+            .collect();
+        if !clearances.is_empty() {
+            props.push(format!(
+                "NULLIF(CONCAT_WS(' ', {}), '') AS clearance_label",
+                clearances.join(", ")
+            ));
+        }
+
+        // Quality and surface pair up by position: NATQUA [1], NATSUR [4, 1] -> "fS.M"
+        if let (Some(sur), Some(qua)) = (list_column("NATSUR"), list_column("NATQUA")) {
+            props.push(format!(
+                "(SELECT string_agg(NULLIF(COALESCE({qua_lookup}[d.{qua}[i]], '') || COALESCE({sur_lookup}[d.{sur}[i]], ''), ''), '.' ORDER BY i) \
+                 FROM generate_series(1, GREATEST(cardinality(d.{sur}), cardinality(d.{qua}))) AS i) AS seabed_label",
+                qua_lookup = sql_code_lookup(NATQUA_ABBREVIATIONS),
+                sur_lookup = sql_code_lookup(NATSUR_ABBREVIATIONS),
+                sur = sur,
+                qua = qua,
+            ));
+        }
+
+        if let (Some(valmag), Some(ryrmgv)) = (column("VALMAG"), column("RYRMGV")) {
+            let year =
+                "EXTRACT(YEAR FROM COALESCE((query_params->>'date')::date, CURRENT_DATE))::integer";
+            let valacm = column("VALACM").map(|c| format!("d.{}", c));
+            let projected = format!(
+                "(d.{} + COALESCE({}, 0) / 60.0 * COALESCE({} - d.{}, 0))",
+                valmag,
+                valacm.as_deref().unwrap_or("NULL"),
+                year,
+                ryrmgv
+            );
+            let annual_change = match &valacm {
+                Some(acm) => format!(
+                    "'(' || ROUND(ABS({0}))::integer || '''' || CASE WHEN {0} < 0 THEN 'W' ELSE 'E' END || ')'",
+                    acm
+                ),
+                None => "NULL".to_string(),
+            };
+
+            props.push(format!(
+                "{}::double precision AS magvar_projected",
+                projected
+            ));
+            props.push(format!(
+                "CASE WHEN d.{valmag} IS NOT NULL THEN CONCAT_WS(' ', {angle}, CASE WHEN d.{ryrmgv} IS NOT NULL THEN {year} END, {change}) END AS magvar_label",
+                valmag = valmag,
+                angle = sql_degrees_minutes(&projected),
+                ryrmgv = ryrmgv,
+                year = year,
+                change = annual_change,
+            ));
+        }
+
+        if let Some(col) = column("CURVEL") {
+            props.push(format!(
+                "trim_scale(ROUND(d.{}, 1)) || ' kn' AS curvel_label",
+                col
+            ));
+        }
+
+        if let Some(col) = column("RESTRN") {
+            props.push(format!(
+                "COALESCE(1 = ANY(d.{}), false) AS anchoring_prohibited",
+                col
+            ));
+        }
+
+        props
+    }
+
+    /// Position of the S-57 RADIUS attribute in `columns`, if the layer stores one.
+    /// Point features with a radius are stored as a circle of that radius.
+    pub fn radius_column_index(&self) -> Option<usize> {
+        self.columns.iter().position(|c| c.s57_field == "RADIUS")
+    }
+
     /// Generate `CREATE TABLE IF NOT EXISTS` DDL matching the standard column layout.
     pub fn create_table_sql(&self) -> String {
         let mut cols = String::new();
@@ -97,6 +329,11 @@ impl LayerDef {
         cols.push_str("    ac TEXT,\n");
         cols.push_str("    lc TEXT,\n");
         cols.push_str("    sy TEXT,\n");
+        cols.push_str("    ap TEXT,\n");
+        cols.push_str("    ls TEXT,\n");
+        cols.push_str("    display_category TEXT,\n");
+        cols.push_str("    viewing_group INTEGER,\n");
+        cols.push_str("    display_priority INTEGER,\n");
         cols.push_str("    sordat TEXT,\n");
         cols.push_str("    sorind TEXT,\n");
         cols.push_str("    attributes JSONB,\n");
@@ -113,6 +350,33 @@ impl LayerDef {
         format!("CREATE TABLE IF NOT EXISTS {} (\n{});", self.table, cols)
     }
 
+    /// Add standard columns introduced after a table was first created, so
+    /// existing databases pick them up without being rebuilt.
+    pub fn add_missing_columns_sql(&self) -> Vec<String> {
+        vec![
+            format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS ap TEXT;",
+                self.table
+            ),
+            format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS ls TEXT;",
+                self.table
+            ),
+            format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS display_category TEXT;",
+                self.table
+            ),
+            format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS viewing_group INTEGER;",
+                self.table
+            ),
+            format!(
+                "ALTER TABLE {} ADD COLUMN IF NOT EXISTS display_priority INTEGER;",
+                self.table
+            ),
+        ]
+    }
+
     /// Generate indexes for layer table, including pre-computed columns for MVT optimization.
     pub fn create_indexes_sql(&self) -> Vec<String> {
         vec![
@@ -153,7 +417,12 @@ impl LayerDef {
         let layer_select_cols: String = self
             .columns
             .iter()
-            .map(|c| format!(",\n            d.{}", c.sql_column))
+            .map(|c| format!(",\n            {}", c.mvt_select()))
+            .collect();
+        let derived: String = self
+            .derived_properties_sql()
+            .iter()
+            .map(|p| format!(",\n            {}", p))
             .collect();
 
         format!(
@@ -184,6 +453,11 @@ BEGIN
             d.ac AS "AC",
             d.lc AS "LC",
             d.sy AS "SY",
+            d.ap AS "AP",
+            d.ls AS "LS",
+            d.display_category AS "DISPCAT",
+            d.viewing_group AS "VIEWGRP",
+            d.display_priority AS "PRIO"{derived},
             d.scamin,
             d.sordat,
             d.attributes
@@ -202,6 +476,7 @@ END;
 $$ LANGUAGE plpgsql STABLE PARALLEL SAFE;"#,
             table = self.table,
             layer_cols = layer_select_cols,
+            derived = derived,
         )
     }
 }
@@ -210,24 +485,29 @@ $$ LANGUAGE plpgsql STABLE PARALLEL SAFE;"#,
 pub enum ColValue {
     Float(Option<f64>),
     Int(Option<i32>),
+    IntList(Option<Vec<i32>>),
     Text(Option<String>),
+    Json(Option<Value>),
 }
 
 /// Build the INSERT...ON CONFLICT upsert SQL for a layer definition.
 ///
 /// Column order: enc_name, feature_fid, edition, update_number, compilation_scale,
-/// scamin, objl, [layer-specific columns...], sordat, sorind, attributes, geom, geom_3857, min_zoom, max_zoom
+/// scamin, objl, [layer-specific columns...], ac, lc, sy, ap, ls, display_category, viewing_group, display_priority, sordat, sorind, attributes, geom, geom_3857, min_zoom, max_zoom
 pub fn build_upsert_sql(def: &LayerDef) -> String {
     let num_common_leading = 7; // enc_name, feature_fid, edition, update_number, compilation_scale, scamin, objl
     let num_layer = def.columns.len();
-    let num_common_trailing = 7; // ac, lc, sy, sordat, sorind, attributes, geom
+    // ac, lc, sy, ap, ls, display_category, viewing_group, display_priority, sordat, sorind,
+    // attributes, geom
+    let num_common_trailing = 12;
     let total = num_common_leading + num_layer + num_common_trailing;
 
     // Column names
     let layer_cols: Vec<&str> = def.columns.iter().map(|c| c.sql_column).collect();
+    let layer_col_list: String = layer_cols.iter().map(|c| format!("{}, ", c)).collect();
     let all_cols = format!(
-        "enc_name, feature_fid, edition, update_number, compilation_scale, scamin, objl, {}, ac, lc, sy, sordat, sorind, attributes, geom, geom_3857, min_zoom, max_zoom",
-        layer_cols.join(", ")
+        "enc_name, feature_fid, edition, update_number, compilation_scale, scamin, objl, {}ac, lc, sy, ap, ls, display_category, viewing_group, display_priority, sordat, sorind, attributes, geom, geom_3857, min_zoom, max_zoom",
+        layer_col_list
     );
 
     // Placeholders $1..$N
@@ -238,6 +518,19 @@ pub fn build_upsert_sql(def: &LayerDef) -> String {
         "ST_MakeValid(ST_Force2D(ST_SetSRID(ST_GeomFromGeoJSON(${}), 4326)))",
         total
     );
+
+    // Points with a RADIUS (e.g. anchor berths) become a circle of that radius in metres
+    let geom_expr = match def.radius_column_index() {
+        Some(idx) => {
+            let radius = format!("${}::double precision", num_common_leading + idx + 1);
+            format!(
+                "CASE WHEN {radius} > 0 AND GeometryType({geom}) = 'POINT' THEN ST_Buffer({geom}::geography, {radius})::geometry ELSE {geom} END",
+                radius = radius,
+                geom = geom_expr
+            )
+        }
+        None => geom_expr,
+    };
     placeholders.push(geom_expr.clone());
 
     // geom_3857: ST_Transform(geom, 3857)
@@ -262,6 +555,11 @@ pub fn build_upsert_sql(def: &LayerDef) -> String {
     update_parts.push("ac = EXCLUDED.ac".to_string());
     update_parts.push("lc = EXCLUDED.lc".to_string());
     update_parts.push("sy = EXCLUDED.sy".to_string());
+    update_parts.push("ap = EXCLUDED.ap".to_string());
+    update_parts.push("ls = EXCLUDED.ls".to_string());
+    update_parts.push("display_category = EXCLUDED.display_category".to_string());
+    update_parts.push("viewing_group = EXCLUDED.viewing_group".to_string());
+    update_parts.push("display_priority = EXCLUDED.display_priority".to_string());
     update_parts.push("sordat = EXCLUDED.sordat".to_string());
     update_parts.push("sorind = EXCLUDED.sorind".to_string());
     update_parts.push("attributes = EXCLUDED.attributes".to_string());
@@ -322,9 +620,19 @@ pub fn extract_values(def: &LayerDef, typed: &Map<String, Value>) -> Vec<ColValu
                     });
                     ColValue::Int(int_val.map(|v| v as i32))
                 }
+                ColType::IntList => {
+                    let list: Vec<i32> = val
+                        .map(crate::util::int_list)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|v| v as i32)
+                        .collect();
+                    ColValue::IntList((!list.is_empty()).then_some(list))
+                }
                 ColType::Text => {
                     ColValue::Text(val.and_then(|v| v.as_str()).map(|s| s.to_string()))
                 }
+                ColType::Json(parse) => ColValue::Json(val.and_then(parse)),
             }
         })
         .collect()
@@ -364,7 +672,9 @@ async fn upsert_feature(
         q = match val {
             ColValue::Float(v) => q.bind(*v),
             ColValue::Int(v) => q.bind(*v),
+            ColValue::IntList(v) => q.bind(v.clone()),
             ColValue::Text(v) => q.bind(v.as_deref()),
+            ColValue::Json(v) => q.bind(v.clone().map(sqlx::types::Json)),
         };
     }
 
@@ -372,7 +682,12 @@ async fn upsert_feature(
     q = q
         .bind(style.ac.as_deref())
         .bind(style.lc.as_deref())
-        .bind(style.sy.as_deref());
+        .bind(style.sy.as_deref())
+        .bind(style.ap.as_deref())
+        .bind(style.ls.as_deref())
+        .bind(style.display_category.map(DisplayCategory::as_str))
+        .bind(style.viewing_group)
+        .bind(style.display_priority);
 
     // Bind common trailing params
     q = q
@@ -385,6 +700,43 @@ async fn upsert_feature(
     Ok(())
 }
 
+/// Add a named feature to the `place_names` gazetteer, with a representative
+/// point and bounding box derived from its geometry.
+async fn insert_place_name(
+    tx: &mut Transaction<'_, Postgres>,
+    ctx: &ChartContext<'_>,
+    def: &LayerDef,
+    fid: i64,
+    common: &CommonAttributes,
+    geom_geojson: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO place_names (enc_name, layer, object_class, feature_fid, objnam, nobjnm, compilation_scale, point, bbox)
+        SELECT $1, $2, $3, $4, $5, $6, $7, ST_PointOnSurface(g), ST_Envelope(g)
+        FROM (SELECT ST_MakeValid(ST_Force2D(ST_SetSRID(ST_GeomFromGeoJSON($8), 4326))) AS g) AS src
+        ON CONFLICT (enc_name, layer, feature_fid) DO UPDATE SET
+            object_class = EXCLUDED.object_class,
+            objnam = EXCLUDED.objnam,
+            nobjnm = EXCLUDED.nobjnm,
+            compilation_scale = EXCLUDED.compilation_scale,
+            point = EXCLUDED.point,
+            bbox = EXCLUDED.bbox
+        "#,
+    )
+    .bind(ctx.enc_name)
+    .bind(def.table)
+    .bind(def.s57_name)
+    .bind(fid)
+    .bind(&common.objnam)
+    .bind(&common.nobjnm)
+    .bind(ctx.metadata.compilation_scale)
+    .bind(geom_geojson)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
 /// Helper: look up a field by name, returning None on missing/error.
 fn get_field(feature: &Feature<'_>, name: &str) -> Option<gdal::vector::FieldValue> {
     let idx = feature.field_index(name).ok()?;
@@ -406,6 +758,20 @@ pub fn extract_common(
     let objl = get_field(feature, "OBJL").and_then(|v| v.into_int());
     let sordat = get_field(feature, "SORDAT").and_then(|v| v.into_string());
     let sorind = get_field(feature, "SORIND").and_then(|v| v.into_string());
+    let objnam = get_field(feature, "OBJNAM").and_then(|v| v.into_string());
+    let nobjnm = get_field(feature, "NOBJNM").and_then(|v| v.into_string());
+
+    let mut other_attributes = Map::new();
+    for &field_name in DOCUMENT_ATTRIBUTES {
+        if let Some(filename) = get_field(feature, field_name).and_then(|v| v.into_string())
+            && !filename.trim().is_empty()
+        {
+            other_attributes.insert(
+                field_name.to_string(),
+                Value::String(filename.trim().into()),
+            );
+        }
+    }
 
     for &field_name in known_fields {
         if let Some(fv) = get_field(feature, field_name) {
@@ -421,7 +787,9 @@ pub fn extract_common(
             objl,
             sordat,
             sorind,
-            other_attributes: Map::new(),
+            objnam,
+            nobjnm,
+            other_attributes,
         },
         typed,
     )
@@ -486,10 +854,13 @@ pub async fn process_layer(
 
             let (common, typed) = extract_common(&feature, &s57_fields);
             let col_values = extract_values(def, &typed);
-            let style = match def.style_fn {
+            let mut style = match def.style_fn {
                 Some(f) => f(&typed),
                 None => StyleProps::default(),
             };
+            style.display_category.get_or_insert(def.display_category);
+            style.viewing_group.get_or_insert(def.viewing_group);
+            style.display_priority.get_or_insert(def.display_priority);
 
             match upsert_feature(
                 &sql,
@@ -507,6 +878,32 @@ pub async fn process_layer(
                 Err(e) => {
                     error!("Failed to upsert {} feature {}: {}", def.s57_name, fid, e);
                     error_count += 1;
+                    continue;
+                }
+            }
+
+            // A failed statement aborts the whole import transaction, so the
+            // gazetteer entry gets a savepoint of its own
+            if common.objnam.is_some() || common.nobjnm.is_some() {
+                let mut savepoint = tx.begin().await?;
+                match insert_place_name(
+                    &mut savepoint,
+                    ctx,
+                    def,
+                    fid,
+                    &common,
+                    geom_geojson.as_deref(),
+                )
+                .await
+                {
+                    Ok(_) => savepoint.commit().await?,
+                    Err(e) => {
+                        savepoint.rollback().await?;
+                        warn!(
+                            "Failed to index name for {} feature {}: {}",
+                            def.s57_name, fid, e
+                        );
+                    }
                 }
             }
         }
@@ -0,0 +1,42 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn achare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Centred anchor symbol with the S-52 plain (dashed magenta) boundary
+    StyleProps {
+        lc: Some("CHMGF".into()),
+        sy: Some("ACHARE51".into()),
+        ls: Some("ACHARE51".into()),
+        ..Default::default()
+    }
+}
+
+pub const ACHARE: LayerDef = LayerDef {
+    s57_name: "ACHARE",
+    table: "achare",
+    columns: &[
+        ColumnDef::new("CATACH", "catach", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26220,
+    display_priority: 3,
+    style_fn: Some(achare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGF"])
+            .with_line_width(2.0)
+            .with_line_dash(DASH)
+            .with_line_styles(&["ACHARE51"]),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 12.0)
+            .with_text_anchor("top")
+            .with_text_offset(0.0, 1.2)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,43 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn achbrt_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Berths with a RADIUS are stored as a circle, which gets the dashed
+    // magenta boundary; the anchor symbol sits at its centre
+    StyleProps {
+        lc: Some("CHMGF".into()),
+        sy: Some("ACHBRT07".into()),
+        ..Default::default()
+    }
+}
+
+pub const ACHBRT: LayerDef = LayerDef {
+    s57_name: "ACHBRT",
+    table: "achbrt",
+    columns: &[
+        ColumnDef::new("CATACH", "catach", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        // Swinging radius in metres; point berths become a circle of this radius
+        ColumnDef::new("RADIUS", "radius", ColType::Float),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26220,
+    display_priority: 5,
+    style_fn: Some(achbrt_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGF"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("top")
+            .with_text_offset(0.0, 1.2)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
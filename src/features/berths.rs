@@ -0,0 +1,37 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn berths_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Berth number symbol with the berth name/number (OBJNAM) centred on it
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        sy: Some("BRTHNO01".into()),
+        ..Default::default()
+    }
+}
+
+pub const BERTHS: LayerDef = LayerDef {
+    s57_name: "BERTHS",
+    table: "berths",
+    columns: &[
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32440,
+    display_priority: 5,
+    style_fn: Some(berths_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("number", StyleLayerType::Text)
+            .with_text("objnam", 10.0)
+            .with_text_anchor("center"),
+    ],
+};
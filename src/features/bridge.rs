@@ -0,0 +1,53 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+/// CATBRG values for opening bridges: opening, swing, lifting, bascule, draw
+const CATBRG_OPENING: &[i64] = &[2, 3, 4, 5, 7];
+
+fn bridge_style(attrs: &Map<String, Value>) -> StyleProps {
+    // Opening bridges get the BRIDGE01 symbol; clearances are labelled
+    // from the derived clearance_label tile property
+    let catbrg = attrs.get("CATBRG").map(int_list).unwrap_or_default();
+    let is_opening = catbrg.iter().any(|v| CATBRG_OPENING.contains(v));
+
+    StyleProps {
+        ac: Some("CHGRF".into()),
+        lc: Some("CHGRD".into()),
+        sy: is_opening.then(|| "BRIDGE01".into()),
+        ..Default::default()
+    }
+}
+
+pub const BRIDGE: LayerDef = LayerDef {
+    s57_name: "BRIDGE",
+    table: "bridge",
+    columns: &[
+        ColumnDef::new("CATBRG", "catbrg", ColType::IntList),
+        ColumnDef::new("VERCLR", "verclr", ColType::Float),
+        ColumnDef::new("VERCCL", "verccl", ColType::Float),
+        ColumnDef::new("VERCOP", "vercop", ColType::Float),
+        ColumnDef::new("HORCLR", "horclr", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 12210,
+    display_priority: 5,
+    style_fn: Some(bridge_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHGRF"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(3.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("clearance", StyleLayerType::Text)
+            .with_text("clearance_label", 11.0)
+            .with_symbol_placement("line-center")
+            .with_text_offset(0.0, -1.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,54 @@
+use serde_json::{Map, Value};
+
+use super::lndmrk::{is_conspicuous, religious_symbol};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+fn buisgl_style(attrs: &Map<String, Value>) -> StyleProps {
+    // Religious buildings by FUNCTN, otherwise the single building symbol;
+    // conspicuous buildings are outlined in black
+    let functn = attrs.get("FUNCTN").map(int_list).unwrap_or_default();
+    let conspicuous = is_conspicuous(attrs);
+
+    let symbol = religious_symbol(&functn, conspicuous).unwrap_or(if conspicuous {
+        "BUISGL11"
+    } else {
+        "BUISGL01"
+    });
+
+    StyleProps {
+        ac: Some("CHBRN".into()),
+        lc: Some(if conspicuous { "CHBLK" } else { "LANDF" }.into()),
+        sy: Some(symbol.into()),
+        ..Default::default()
+    }
+}
+
+pub const BUISGL: LayerDef = LayerDef {
+    s57_name: "BUISGL",
+    table: "buisgl",
+    columns: &[
+        ColumnDef::new("FUNCTN", "functn", ColType::IntList),
+        ColumnDef::new("CONVIS", "convis", ColType::Int),
+        ColumnDef::new("HEIGHT", "height", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32220,
+    display_priority: 2,
+    style_fn: Some(buisgl_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHBRN"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["LANDF", "CHBLK"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
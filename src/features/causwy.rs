@@ -0,0 +1,48 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn causwy_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // The solid and dashed (CONDTN 1, 2 or WATLEV 4, 5) outlines are
+    // selected by the style layer filters below
+    StyleProps {
+        ac: Some("CHBRN".into()),
+        lc: Some("CSTLN".into()),
+        ..Default::default()
+    }
+}
+
+pub const CAUSWY: LayerDef = LayerDef {
+    s57_name: "CAUSWY",
+    table: "causwy",
+    columns: &[
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("WATLEV", "watlev", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22010,
+    display_priority: 4,
+    style_fn: Some(causwy_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHBRN"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(3.0)
+            .with_filter(
+                r#"["!", ["any",
+                    ["in", ["get", "condtn"], ["literal", [1, 2]]],
+                    ["in", ["get", "watlev"], ["literal", [4, 5]]]]]"#,
+            ),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(2.0)
+            .with_line_dash(DASH)
+            .with_filter(
+                r#"["any",
+                    ["in", ["get", "condtn"], ["literal", [1, 2]]],
+                    ["in", ["get", "watlev"], ["literal", [4, 5]]]]"#,
+            ),
+    ],
+};
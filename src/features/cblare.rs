@@ -0,0 +1,37 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn cblare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Anchoring and fishing restrictions are exposed as the `anchoring_prohibited`
+    // tile property for overlays rather than changing the cable area symbol
+    StyleProps {
+        lc: Some("CHMGD".into()),
+        sy: Some("CBLARE51".into()),
+        ls: Some("CBLARE51".into()),
+        ..Default::default()
+    }
+}
+
+pub const CBLARE: LayerDef = LayerDef {
+    s57_name: "CBLARE",
+    table: "cblare",
+    columns: &[
+        ColumnDef::new("CATCBL", "catcbl", ColType::Int),
+        ColumnDef::new("RESTRN", "restrn", ColType::IntList),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26240,
+    display_priority: 3,
+    style_fn: Some(cblare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGD"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_line_styles(&["CBLARE51"]),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
@@ -0,0 +1,40 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn cblohd_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // S-52 CBLOHD01: dashed grey overhead cable with clearance label
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        ..Default::default()
+    }
+}
+
+pub const CBLOHD: LayerDef = LayerDef {
+    s57_name: "CBLOHD",
+    table: "cblohd",
+    columns: &[
+        ColumnDef::new("CATCBL", "catcbl", ColType::Int),
+        ColumnDef::new("VERCLR", "verclr", ColType::Float),
+        ColumnDef::new("VERCSA", "vercsa", ColType::Float),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 12210,
+    display_priority: 4,
+    style_fn: Some(cblohd_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("clearance", StyleLayerType::Text)
+            .with_text("clearance_label", 11.0)
+            .with_symbol_placement("line")
+            .with_text_offset(0.0, -0.8)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
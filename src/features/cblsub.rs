@@ -0,0 +1,31 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn cblsub_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("CHMGD".into()),
+        ls: Some("CBLSUB06".into()),
+        ..Default::default()
+    }
+}
+
+pub const CBLSUB: LayerDef = LayerDef {
+    s57_name: "CBLSUB",
+    table: "cblsub",
+    columns: &[
+        ColumnDef::new("CATCBL", "catcbl", ColType::Int),
+        ColumnDef::new("BURDEP", "burdep", ColType::Float),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34070,
+    display_priority: 4,
+    style_fn: Some(cblsub_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["CHMGD"])
+        .with_line_width(1.5)
+        .with_line_styles(&["CBLSUB06"])],
+};
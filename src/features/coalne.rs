@@ -0,0 +1,48 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, DOTT, StyleLayerDef, StyleLayerType};
+
+fn coalne_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // CATCOA line styles are selected by the style layer filters below:
+    // heavy for steep coasts, dashed where the coastline is ice, dotted
+    // where mangrove or marsh leaves it indefinite, plain otherwise. LNDARE
+    // areas are not outlined, so these edges are drawn only once.
+    StyleProps {
+        lc: Some("CSTLN".into()),
+        ..Default::default()
+    }
+}
+
+pub const COALNE: LayerDef = LayerDef {
+    s57_name: "COALNE",
+    table: "coalne",
+    columns: &[
+        ColumnDef::new("CATCOA", "catcoa", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 12410,
+    display_priority: 4,
+    style_fn: Some(coalne_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.5)
+            .with_filter(r#"["!", ["in", ["get", "catcoa"], ["literal", [1, 6, 7, 8, 10]]]]"#),
+        StyleLayerDef::new("line_steep", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(2.5)
+            .with_filter(r#"["==", ["get", "catcoa"], 1]"#),
+        StyleLayerDef::new("line_ice", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_filter(r#"["in", ["get", "catcoa"], ["literal", [6, 10]]]"#),
+        StyleLayerDef::new("line_indefinite", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.5)
+            .with_line_dash(DOTT)
+            .with_filter(r#"["in", ["get", "catcoa"], ["literal", [7, 8]]]"#),
+    ],
+};
@@ -0,0 +1,36 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn ctnare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // S-57 gives CTNARE no RESTRN or CATREA, so S-52 always draws the
+    // cautionary area symbol and boundary
+    StyleProps {
+        lc: Some("CHMGD".into()),
+        sy: Some("CTYARE51".into()),
+        ls: Some("CTYARE51".into()),
+        ..Default::default()
+    }
+}
+
+pub const CTNARE: LayerDef = LayerDef {
+    s57_name: "CTNARE",
+    table: "ctnare",
+    columns: &[
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26050,
+    display_priority: 3,
+    style_fn: Some(ctnare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGD"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_line_styles(&["CTYARE51"]),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
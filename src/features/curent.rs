@@ -0,0 +1,35 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn curent_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Non-tidal current arrow, rotated by ORIENT in the tile style
+    StyleProps {
+        sy: Some("CURENT01".into()),
+        ..Default::default()
+    }
+}
+
+pub const CURENT: LayerDef = LayerDef {
+    s57_name: "CURENT",
+    table: "curent",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("CURVEL", "curvel", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(curent_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+        StyleLayerDef::new("velocity", StyleLayerType::Text)
+            .with_text("curvel_label", 10.0)
+            .with_text_anchor("top")
+            .with_text_offset(0.0, 1.2)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
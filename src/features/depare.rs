@@ -1,24 +1,31 @@
 use serde_json::{Map, Value};
 
-use crate::feature::{ColType, ColumnDef, LayerDef, StyleProps};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
 use crate::style::{StyleLayerDef, StyleLayerType};
 
+/// Safety contour in metres: the boundary between the DEPMS and DEPMD depth
+/// zones, and the depth S-52 treats as dangerous for isolated obstructions
+pub(super) const SAFETY_CONTOUR: f64 = 6.0;
+
+/// S-52 depth-zone colour token for a depth range, shared with DRGARE
+pub(super) fn depth_zone_color(drval1: Option<f64>, drval2: Option<f64>) -> &'static str {
+    match (drval1, drval2) {
+        (Some(d1), Some(d2)) if d1 < 0.0 && d2 <= 0.0 => "DEPIT",
+        (Some(d1), _) if d1 <= 3.0 => "DEPVS",
+        (Some(d1), _) if d1 <= SAFETY_CONTOUR => "DEPMS",
+        (Some(d1), _) if d1 <= 9.0 => "DEPMD",
+        (Some(d1), _) if d1 > 9.0 => "DEPDW",
+        _ => "DEPDW", // Default to deep water when depth range is unknown
+    }
+}
+
 fn depare_style(attrs: &Map<String, Value>) -> StyleProps {
     let drval1 = attrs.get("DRVAL1").and_then(|v| v.as_f64());
     let drval2 = attrs.get("DRVAL2").and_then(|v| v.as_f64());
-    let ac = match (drval1, drval2) {
-        (Some(d1), Some(d2)) if d1 < 0.0 && d2 <= 0.0 => Some("DEPIT"),
-        (Some(d1), _) if d1 <= 3.0 => Some("DEPVS"),
-        (Some(d1), _) if d1 <= 6.0 => Some("DEPMS"),
-        (Some(d1), _) if d1 <= 9.0 => Some("DEPMD"),
-        (Some(d1), _) if d1 > 9.0 => Some("DEPDW"),
-        _ => Some("DEPDW"), // Default to deep water when depth range is unknown
-    }
-    .map(String::from);
     StyleProps {
-        ac,
+        ac: Some(depth_zone_color(drval1, drval2).into()),
         lc: Some("CHGRD".into()),
-        sy: None,
+        ..Default::default()
     }
 }
 
@@ -29,6 +36,9 @@ pub const DEPARE: LayerDef = LayerDef {
         ColumnDef::new("DRVAL1", "drval1", ColType::Float),
         ColumnDef::new("DRVAL2", "drval2", ColType::Float),
     ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 13030,
+    display_priority: 1,
     style_fn: Some(depare_style),
     style_layers: &[
         StyleLayerDef::new("fill", StyleLayerType::Fill)
@@ -0,0 +1,47 @@
+use serde_json::{Map, Value};
+
+use super::depare::depth_zone_color;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn drgare_style(attrs: &Map<String, Value>) -> StyleProps {
+    // S-52 shades a dredged area by its maintained depth (DRVAL1, with
+    // DRVAL2 defaulting to it) and sets it apart from the surrounding depth
+    // areas with the DRGARE01 pattern and a dashed grey boundary
+    let drval1 = attrs.get("DRVAL1").and_then(|v| v.as_f64());
+    let drval2 = attrs.get("DRVAL2").and_then(|v| v.as_f64()).or(drval1);
+
+    StyleProps {
+        ac: Some(depth_zone_color(drval1, drval2).into()),
+        lc: Some("CHGRF".into()),
+        ap: Some("DRGARE01P".into()),
+        ..Default::default()
+    }
+}
+
+pub const DRGARE: LayerDef = LayerDef {
+    s57_name: "DRGARE",
+    table: "drgare",
+    columns: &[
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("DRVAL2", "drval2", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 13030,
+    display_priority: 1,
+    style_fn: Some(drgare_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill)
+            .with_colors(&["DEPIT", "DEPVS", "DEPMS", "DEPMD", "DEPDW"]),
+        StyleLayerDef::new("pattern", StyleLayerType::Pattern),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRF"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("depth", StyleLayerType::Text)
+            .with_text("drval1", 11.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
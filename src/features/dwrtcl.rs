@@ -0,0 +1,47 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+/// TRAFIC value for two-way traffic
+const TRAFIC_TWO_WAY: i64 = 4;
+
+/// Deep water route style, shared with DWRTPT: reciprocal arrows (DWRUTE51) for
+/// two-way routes, a one-way arrow otherwise, both rotated by ORIENT
+pub(super) fn dwrtcl_style(attrs: &Map<String, Value>) -> StyleProps {
+    let has_orient = attrs.get("ORIENT").and_then(|v| v.as_f64()).is_some();
+    let trafic = attrs.get("TRAFIC").and_then(|v| v.as_i64());
+
+    let sy = match (has_orient, trafic) {
+        (true, Some(TRAFIC_TWO_WAY)) => Some("DWRUTE51"),
+        (true, _) => Some("TSSLPT51"),
+        (false, _) => None,
+    };
+
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        sy: sy.map(String::from),
+        ..Default::default()
+    }
+}
+
+pub const DWRTCL: LayerDef = LayerDef {
+    s57_name: "DWRTCL",
+    table: "dwrtcl",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("TRAFIC", "trafic", ColType::Int),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25010,
+    display_priority: 6,
+    style_fn: Some(dwrtcl_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+    ],
+};
@@ -0,0 +1,24 @@
+use super::dwrtcl::dwrtcl_style;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+pub const DWRTPT: LayerDef = LayerDef {
+    s57_name: "DWRTPT",
+    table: "dwrtpt",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("TRAFIC", "trafic", ColType::Int),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25010,
+    display_priority: 6,
+    style_fn: Some(dwrtcl_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+    ],
+};
@@ -0,0 +1,42 @@
+use serde_json::{Map, Value};
+
+use super::dwrtcl::dwrtcl_style;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn fairwy_style(attrs: &Map<String, Value>) -> StyleProps {
+    // Direction arrows as for deep water routes, inside a grey dashed boundary
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        ..dwrtcl_style(attrs)
+    }
+}
+
+pub const FAIRWY: LayerDef = LayerDef {
+    s57_name: "FAIRWY",
+    table: "fairwy",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("TRAFIC", "trafic", ColType::Int),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26050,
+    display_priority: 2,
+    style_fn: Some(fairwy_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("top")
+            .with_text_offset(0.0, 1.2)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,50 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+fn hrbfac_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATHAF: 1 RoRo terminal, 4 fishing harbour, 5 yacht harbour/marina;
+    // other categories only get the dashed boundary and name
+    let cathaf = attrs.get("CATHAF").map(int_list).unwrap_or_default();
+    let symbol = cathaf.iter().find_map(|cat| match cat {
+        1 => Some("ROLROL01"),
+        4 => Some("HRBFAC09"),
+        5 => Some("SMCFAC02"),
+        _ => None,
+    });
+
+    StyleProps {
+        lc: Some("CHBLK".into()),
+        sy: symbol.map(String::from),
+        ..Default::default()
+    }
+}
+
+pub const HRBFAC: LayerDef = LayerDef {
+    s57_name: "HRBFAC",
+    table: "hrbfac",
+    columns: &[
+        ColumnDef::new("CATHAF", "cathaf", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32410,
+    display_priority: 5,
+    style_fn: Some(hrbfac_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,34 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn hulkes_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        ac: Some("CHBRN".into()),
+        lc: Some("CSTLN".into()),
+        sy: Some("HULKES01".into()),
+        ..Default::default()
+    }
+}
+
+pub const HULKES: LayerDef = LayerDef {
+    s57_name: "HULKES",
+    table: "hulkes",
+    columns: &[
+        ColumnDef::new("CATHLK", "cathlk", ColType::IntList),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22010,
+    display_priority: 4,
+    style_fn: Some(hulkes_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHBRN"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.5),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
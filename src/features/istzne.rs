@@ -0,0 +1,27 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn istzne_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        ..Default::default()
+    }
+}
+
+pub const ISTZNE: LayerDef = LayerDef {
+    s57_name: "ISTZNE",
+    table: "istzne",
+    columns: &[
+        ColumnDef::new("CATTSS", "cattss", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25010,
+    display_priority: 3,
+    style_fn: Some(istzne_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["TRFCD"])
+        .with_line_width(1.0)],
+};
@@ -1,7 +1,7 @@
 use serde_json::{Map, Value};
 
 use crate::colors::{parse_colours, Colour};
-use crate::feature::{ColType, ColumnDef, LayerDef, StyleProps};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
 use crate::style::{StyleLayerDef, StyleLayerType};
 
 fn lights_style(attrs: &Map<String, Value>) -> StyleProps {
@@ -28,9 +28,8 @@ fn lights_style(attrs: &Map<String, Value>) -> StyleProps {
     };
 
     StyleProps {
-        ac: None,
-        lc: None,
         sy: Some(symbol.into()),
+        ..Default::default()
     }
 }
 
@@ -46,6 +45,9 @@ pub const LIGHTS: LayerDef = LayerDef {
         ColumnDef::new("HEIGHT", "height", ColType::Float),
         ColumnDef::new("OBJNAM", "objnam", ColType::Text),
     ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 27070,
+    display_priority: 8,
     style_fn: Some(lights_style),
     style_layers: &[StyleLayerDef::new("icon", StyleLayerType::Icon)],
 };
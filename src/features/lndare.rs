@@ -1,6 +1,6 @@
 use serde_json::{Map, Value};
 
-use crate::feature::{ColType, ColumnDef, LayerDef, StyleProps};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
 use crate::style::{StyleLayerDef, StyleLayerType};
 
 fn lndare_style(_attrs: &Map<String, Value>) -> StyleProps {
@@ -8,6 +8,7 @@ fn lndare_style(_attrs: &Map<String, Value>) -> StyleProps {
         ac: Some("LANDA".into()),
         lc: Some("CSTLN".into()),
         sy: Some("LNDARE01".into()),
+        ..Default::default()
     }
 }
 
@@ -20,12 +21,18 @@ pub const LNDARE: LayerDef = LayerDef {
         ColumnDef::new("NATSUR", "natsur", ColType::Int),
         ColumnDef::new("NATQUA", "natqua", ColType::Int),
     ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 12010,
+    display_priority: 1,
     style_fn: Some(lndare_style),
     style_layers: &[
         StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["LANDA"]),
+        // Area outlines are left to COALNE/SLCONS, which carry the coastline
+        // category (S-52 draws LNDARE areas with AC(LANDA) only)
         StyleLayerDef::new("line", StyleLayerType::Line)
             .with_colors(&["CSTLN"])
-            .with_line_width(2.0),
+            .with_line_width(2.0)
+            .with_filter(r#"["==", ["geometry-type"], "LineString"]"#),
         StyleLayerDef::new("icon", StyleLayerType::Icon),
     ],
 };
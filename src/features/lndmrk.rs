@@ -0,0 +1,97 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+/// CONVIS value for a visually conspicuous feature
+const CONVIS_CONSPICUOUS: i64 = 1;
+
+/// Whether CONVIS marks the feature as visually conspicuous
+pub(super) fn is_conspicuous(attrs: &Map<String, Value>) -> bool {
+    attrs.get("CONVIS").and_then(|v| v.as_i64()) == Some(CONVIS_CONSPICUOUS)
+}
+
+/// Religious building symbol from FUNCTN (church/chapel, other temples,
+/// mosque/marabout), shared with BUISGL
+pub(super) fn religious_symbol(functn: &[i64], conspicuous: bool) -> Option<&'static str> {
+    let (plain, consp) = if functn.iter().any(|f| matches!(f, 20 | 21)) {
+        ("BUIREL01", "BUIREL13")
+    } else if functn.iter().any(|f| matches!(f, 22..=25)) {
+        ("BUIREL04", "BUIREL14")
+    } else if functn.iter().any(|f| matches!(f, 26 | 27)) {
+        ("BUIREL05", "BUIREL15")
+    } else {
+        return None;
+    };
+    Some(if conspicuous { consp } else { plain })
+}
+
+fn lndmrk_style(attrs: &Map<String, Value>) -> StyleProps {
+    // Symbol from CATLMK (first matching category) with the conspicuous
+    // variant when CONVIS = 1. Categories without a non-conspicuous sprite
+    // fall back to the generic position symbol.
+    let catlmk = attrs.get("CATLMK").map(int_list).unwrap_or_default();
+    let functn = attrs.get("FUNCTN").map(int_list).unwrap_or_default();
+    let conspicuous = is_conspicuous(attrs);
+
+    let by_category = catlmk.iter().find_map(|cat| {
+        let (plain, consp) = match cat {
+            1 => ("CAIRNS01", "CAIRNS11"),
+            3 => ("CHIMNY01", "CHIMNY11"),
+            4 => ("POSGEN01", "DSHAER11"),
+            5 => ("FLGSTF01", "FLGSTF01"),
+            6 => ("FLASTK01", "FLASTK11"),
+            7 => ("MSTCON04", "MSTCON14"),
+            9 | 10 | 12 | 13 => ("POSGEN01", "MONUMT12"),
+            15 => ("DOMES001", "DOMES011"),
+            16 => ("RASCAN01", "RASCAN11"),
+            17 => ("TOWERS01", "TOWERS03"),
+            18 => ("POSGEN01", "WNDMIL12"),
+            19 => ("WIMCON01", "WIMCON11"),
+            20 => {
+                return religious_symbol(&functn, conspicuous).or(Some(if conspicuous {
+                    "BUIREL13"
+                } else {
+                    "BUIREL01"
+                }));
+            }
+            _ => return None,
+        };
+        Some(if conspicuous { consp } else { plain })
+    });
+
+    let symbol = by_category
+        .or_else(|| religious_symbol(&functn, conspicuous))
+        .unwrap_or(if conspicuous { "POSGEN03" } else { "POSGEN01" });
+
+    StyleProps {
+        sy: Some(symbol.into()),
+        ..Default::default()
+    }
+}
+
+pub const LNDMRK: LayerDef = LayerDef {
+    s57_name: "LNDMRK",
+    table: "lndmrk",
+    columns: &[
+        ColumnDef::new("CATLMK", "catlmk", ColType::IntList),
+        ColumnDef::new("FUNCTN", "functn", ColType::IntList),
+        ColumnDef::new("CONVIS", "convis", ColType::Int),
+        ColumnDef::new("HEIGHT", "height", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22220,
+    display_priority: 6,
+    style_fn: Some(lndmrk_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,39 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+fn lndrgn_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATLND: 2 marsh, 12 swamp; other land regions are only named
+    let catlnd = attrs.get("CATLND").map(int_list).unwrap_or_default();
+    let pattern = catlnd
+        .iter()
+        .any(|cat| matches!(cat, 2 | 12))
+        .then_some("MARSHES1P");
+
+    StyleProps {
+        ap: pattern.map(String::from),
+        ..Default::default()
+    }
+}
+
+pub const LNDRGN: LayerDef = LayerDef {
+    s57_name: "LNDRGN",
+    table: "lndrgn",
+    columns: &[
+        ColumnDef::new("CATLND", "catlnd", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32030,
+    display_priority: 2,
+    style_fn: Some(lndrgn_style),
+    style_layers: &[
+        StyleLayerDef::new("pattern", StyleLayerType::Pattern),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
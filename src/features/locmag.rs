@@ -0,0 +1,29 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn locmag_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        sy: Some("LOCMAG01".into()),
+        ..Default::default()
+    }
+}
+
+pub const LOCMAG: LayerDef = LayerDef {
+    s57_name: "LOCMAG",
+    table: "locmag",
+    columns: &[ColumnDef::new("VALLMA", "vallma", ColType::Float)],
+    display_category: DisplayCategory::Other,
+    viewing_group: 31080,
+    display_priority: 5,
+    style_fn: Some(locmag_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
@@ -0,0 +1,38 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn m_qual_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATZOC: 1 A1, 2 A2, 3 B, 4 C, 5 D, 6 U (data not assessed). The
+    // stars of the zone of confidence are repeated over the whole area.
+    let pattern = match attrs.get("CATZOC").and_then(|v| v.as_i64()) {
+        Some(1) => "DQUALA11P",
+        Some(2) => "DQUALA21P",
+        Some(3) => "DQUALB01P",
+        Some(4) => "DQUALC01P",
+        Some(5) => "DQUALD01P",
+        _ => "DQUALU01P",
+    };
+
+    StyleProps {
+        ap: Some(pattern.into()),
+        ..Default::default()
+    }
+}
+
+pub const M_QUAL: LayerDef = LayerDef {
+    s57_name: "M_QUAL",
+    table: "m_qual",
+    columns: &[
+        ColumnDef::new("CATZOC", "catzoc", ColType::Int),
+        ColumnDef::new("POSACC", "posacc", ColType::Float),
+        ColumnDef::new("SURSTA", "sursta", ColType::Text),
+        ColumnDef::new("SUREND", "surend", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 31010,
+    display_priority: 2,
+    style_fn: Some(m_qual_style),
+    style_layers: &[StyleLayerDef::new("pattern", StyleLayerType::Pattern)],
+};
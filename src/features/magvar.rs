@@ -0,0 +1,40 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn magvar_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // The label is projected to the request date in the tile function (magvar_label)
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        sy: Some("MAGVAR01".into()),
+        ..Default::default()
+    }
+}
+
+pub const MAGVAR: LayerDef = LayerDef {
+    s57_name: "MAGVAR",
+    table: "magvar",
+    columns: &[
+        ColumnDef::new("VALMAG", "valmag", ColType::Float),
+        ColumnDef::new("RYRMGV", "ryrmgv", ColType::Int),
+        ColumnDef::new("VALACM", "valacm", ColType::Float),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 31080,
+    display_priority: 4,
+    style_fn: Some(magvar_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("magvar_label", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,47 @@
+use serde_json::{Map, Value};
+
+use super::resare::restriction_symbology;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+fn mipare_style(attrs: &Map<String, Value>) -> StyleProps {
+    // S-52 draws MIPARE as a cautionary area; RESTRN replaces the symbol and
+    // boundary with the restriction's (RESCSP02)
+    let restrn = attrs.get("RESTRN").map(int_list).unwrap_or_default();
+    let (symbol, line_style) = if restrn.is_empty() {
+        ("CTYARE51", "CTYARE51")
+    } else {
+        restriction_symbology(&restrn, &[])
+    };
+
+    StyleProps {
+        lc: Some("CHMGD".into()),
+        sy: Some(symbol.into()),
+        ls: Some(line_style.into()),
+        ..Default::default()
+    }
+}
+
+pub const MIPARE: LayerDef = LayerDef {
+    s57_name: "MIPARE",
+    table: "mipare",
+    columns: &[
+        ColumnDef::new("CATMPA", "catmpa", ColType::IntList),
+        ColumnDef::new("RESTRN", "restrn", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26040,
+    display_priority: 3,
+    style_fn: Some(mipare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGD"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_line_styles(&["ENTRES51", "ACHRES51", "FSHRES51", "CTYARE51"]),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
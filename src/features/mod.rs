@@ -1,15 +1,139 @@
+mod achare;
+mod achbrt;
+mod berths;
+mod bridge;
+mod buisgl;
+mod causwy;
+mod cblare;
+mod cblohd;
+mod cblsub;
+mod coalne;
+mod ctnare;
+mod curent;
 mod depare;
+mod drgare;
+mod dwrtcl;
+mod dwrtpt;
+mod fairwy;
+mod hrbfac;
+mod hulkes;
+mod istzne;
 mod lights;
 mod lndare;
+mod lndmrk;
+mod lndrgn;
+mod locmag;
+mod m_qual;
+mod magvar;
+mod mipare;
+mod morfac;
+mod navlne;
+mod obstrn;
+mod pipare;
+mod pipohd;
+mod pipsol;
+mod ponton;
+mod prcare;
+mod rcrtcl;
+mod rctlpt;
+mod rectrc;
+mod resare;
+mod sbdare;
+mod siltnk;
+mod slcons;
+mod smcfac;
+mod sndwav;
 mod soundg;
+mod spring;
+mod t_hmon;
+mod t_nhmn;
+mod t_tims;
+mod ts_feb;
+mod ts_pad;
+mod ts_pnh;
+mod ts_tis;
+mod tselne;
+mod tsezne;
+mod tssbnd;
+mod tsslpt;
+mod tssron;
+mod unsare;
+mod vegatn;
+mod wedklp;
 
+pub use achare::ACHARE;
+pub use achbrt::ACHBRT;
+pub use berths::BERTHS;
+pub use bridge::BRIDGE;
+pub use buisgl::BUISGL;
+pub use causwy::CAUSWY;
+pub use cblare::CBLARE;
+pub use cblohd::CBLOHD;
+pub use cblsub::CBLSUB;
+pub use coalne::COALNE;
+pub use ctnare::CTNARE;
+pub use curent::CURENT;
 pub use depare::DEPARE;
+pub use drgare::DRGARE;
+pub use dwrtcl::DWRTCL;
+pub use dwrtpt::DWRTPT;
+pub use fairwy::FAIRWY;
+pub use hrbfac::HRBFAC;
+pub use hulkes::HULKES;
+pub use istzne::ISTZNE;
 pub use lights::LIGHTS;
 pub use lndare::LNDARE;
+pub use lndmrk::LNDMRK;
+pub use lndrgn::LNDRGN;
+pub use locmag::LOCMAG;
+pub use m_qual::M_QUAL;
+pub use magvar::MAGVAR;
+pub use mipare::MIPARE;
+pub use morfac::MORFAC;
+pub use navlne::NAVLNE;
+pub use obstrn::OBSTRN;
+pub use pipare::PIPARE;
+pub use pipohd::PIPOHD;
+pub use pipsol::PIPSOL;
+pub use ponton::PONTON;
+pub use prcare::PRCARE;
+pub use rcrtcl::RCRTCL;
+pub use rctlpt::RCTLPT;
+pub use rectrc::RECTRC;
+pub use resare::RESARE;
+pub use sbdare::SBDARE;
+pub use siltnk::SILTNK;
+pub use slcons::SLCONS;
+pub use smcfac::SMCFAC;
+pub use sndwav::SNDWAV;
 pub use soundg::SOUNDG;
+pub use spring::SPRING;
+pub use t_hmon::T_HMON;
+pub use t_nhmn::T_NHMN;
+pub use t_tims::T_TIMS;
+pub use ts_feb::TS_FEB;
+pub use ts_pad::TS_PAD;
+pub use ts_pnh::TS_PNH;
+pub use ts_tis::TS_TIS;
+pub use tselne::TSELNE;
+pub use tsezne::TSEZNE;
+pub use tssbnd::TSSBND;
+pub use tsslpt::TSSLPT;
+pub use tssron::TSSRON;
+pub use unsare::UNSARE;
+pub use vegatn::VEGATN;
+pub use wedklp::WEDKLP;
 
 use crate::feature::LayerDef;
 
 pub fn all_layers() -> &'static [&'static LayerDef] {
-    &[&DEPARE, &LNDARE, &LIGHTS, &SOUNDG]
+    &[
+        &DEPARE, &DRGARE, &UNSARE, &LNDARE, &LNDRGN, &VEGATN, &COALNE, &SLCONS, &PONTON, &HULKES,
+        &CAUSWY, &BUISGL, &SILTNK, &FAIRWY, &TSEZNE, &TSSBND, &TSELNE, &TSSRON, &ISTZNE, &PRCARE,
+        &RESARE, &MIPARE, &CTNARE, &M_QUAL, &ACHARE, &ACHBRT, &CBLARE, &PIPARE, &CBLSUB, &PIPSOL,
+        &CBLOHD, &PIPOHD, &BRIDGE, &HRBFAC, &SMCFAC, &BERTHS, &MORFAC, &NAVLNE, &RECTRC, &RCRTCL,
+        &TSSLPT, &DWRTPT, &DWRTCL, &RCTLPT, &LNDMRK, &CURENT, &TS_FEB, &TS_PAD, &TS_PNH, &TS_TIS,
+        &T_HMON, &T_NHMN, &T_TIMS, &SBDARE, &WEDKLP, &SNDWAV, &SPRING, &OBSTRN, &MAGVAR, &LOCMAG,
+        &LIGHTS, &SOUNDG,
+    ]
 }
@@ -0,0 +1,43 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn morfac_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATMOR: 1 dolphin, 2 deviation dolphin, 7 mooring buoy. Tie-up walls,
+    // chains and other line/area facilities are drawn as a grey line.
+    let symbol = match attrs.get("CATMOR").and_then(|v| v.as_i64()) {
+        Some(1) => Some("MORFAC03"),
+        Some(2) => Some("MORFAC04"),
+        Some(7) => Some("BOYMOR11"),
+        _ => None,
+    };
+
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        sy: symbol.map(String::from),
+        ..Default::default()
+    }
+}
+
+pub const MORFAC: LayerDef = LayerDef {
+    s57_name: "MORFAC",
+    table: "morfac",
+    columns: &[
+        ColumnDef::new("CATMOR", "catmor", ColType::Int),
+        ColumnDef::new("BOYSHP", "boyshp", ColType::Int),
+        ColumnDef::new("COLOUR", "colour", ColType::IntList),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22010,
+    display_priority: 5,
+    style_fn: Some(morfac_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(2.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
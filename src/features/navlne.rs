@@ -0,0 +1,44 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn navlne_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("CHBLK".into()),
+        ..Default::default()
+    }
+}
+
+pub const NAVLNE: LayerDef = LayerDef {
+    s57_name: "NAVLNE",
+    table: "navlne",
+    columns: &[
+        ColumnDef::new("CATNAV", "catnav", ColType::Int),
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25020,
+    display_priority: 4,
+    style_fn: Some(navlne_style),
+    style_layers: &[
+        // CATNAV: 1 clearing line (dashed), 2 transit line, 3 leading line
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_filter(r#"["!=", ["get", "catnav"], 1]"#),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH)
+            .with_filter(r#"["==", ["get", "catnav"], 1]"#),
+        StyleLayerDef::new("bearing", StyleLayerType::Text)
+            .with_text("bearing_label", 11.0)
+            .with_symbol_placement("line")
+            .with_text_offset(0.0, -1.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
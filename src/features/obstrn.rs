@@ -0,0 +1,70 @@
+use serde_json::{Map, Value};
+
+use super::depare::SAFETY_CONTOUR;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DOTT, StyleLayerDef, StyleLayerType};
+
+fn obstrn_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATOBS: 6 foul area, 7 foul ground; other obstructions by WATLEV
+    let catobs = attrs.get("CATOBS").and_then(|v| v.as_i64());
+    let watlev = attrs.get("WATLEV").and_then(|v| v.as_i64());
+    let valsou = attrs.get("VALSOU").and_then(|v| v.as_f64());
+
+    let foul = matches!(catobs, Some(6) | Some(7));
+    let dries = matches!(watlev, Some(1 | 2 | 4 | 5));
+
+    // S-52 isolated danger: a submerged obstruction shallower than the
+    // safety contour. The surrounding depth is not known at import, so
+    // every such obstruction is treated as isolated.
+    let isolated_danger = !foul && !dries && valsou.is_some_and(|depth| depth <= SAFETY_CONTOUR);
+
+    let (symbol, pattern) = match catobs {
+        Some(6) => (None, Some("FOULAR01P")),
+        Some(7) => (Some("FOULGND1"), Some("FOULAR01P")),
+        _ if isolated_danger => (Some("ISODGR01"), None),
+        _ => match watlev {
+            Some(1) | Some(2) => (Some("OBSTRN11"), None),
+            Some(4) | Some(5) => (Some("OBSTRN03"), None),
+            _ => (Some("OBSTRN01"), None),
+        },
+    };
+
+    // Obstructions that dry, cover and uncover, or lie shallower than the
+    // safety contour are a danger to any vessel, so they move up to Display
+    // Base (S-52 viewing group 14010) and are drawn, and win symbol
+    // collisions, at hazard priority
+    let danger = !foul && (dries || isolated_danger);
+
+    StyleProps {
+        lc: Some("CHBLK".into()),
+        sy: symbol.map(String::from),
+        ap: pattern.map(String::from),
+        display_category: danger.then_some(DisplayCategory::Base),
+        viewing_group: danger.then_some(14010),
+        display_priority: danger.then_some(8),
+        ..Default::default()
+    }
+}
+
+pub const OBSTRN: LayerDef = LayerDef {
+    s57_name: "OBSTRN",
+    table: "obstrn",
+    columns: &[
+        ColumnDef::new("CATOBS", "catobs", ColType::Int),
+        ColumnDef::new("VALSOU", "valsou", ColType::Float),
+        ColumnDef::new("WATLEV", "watlev", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34050,
+    display_priority: 5,
+    style_fn: Some(obstrn_style),
+    style_layers: &[
+        StyleLayerDef::new("pattern", StyleLayerType::Pattern),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_line_dash(DOTT),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
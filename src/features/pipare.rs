@@ -0,0 +1,33 @@
+use serde_json::{Map, Value};
+
+use super::pipsol::{pipeline_colour, pipeline_line_style};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn pipare_style(attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some(pipeline_colour(attrs).into()),
+        ls: Some(pipeline_line_style(attrs).into()),
+        ..Default::default()
+    }
+}
+
+pub const PIPARE: LayerDef = LayerDef {
+    s57_name: "PIPARE",
+    table: "pipare",
+    columns: &[
+        ColumnDef::new("CATPIP", "catpip", ColType::IntList),
+        ColumnDef::new("PRODCT", "prodct", ColType::IntList),
+        ColumnDef::new("RESTRN", "restrn", ColType::IntList),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26240,
+    display_priority: 3,
+    style_fn: Some(pipare_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["CHMGD", "CHGRD"])
+        .with_line_width(1.5)
+        .with_line_dash(DASH)
+        .with_line_styles(&["PIPSOL05", "PIPSOL06"])],
+};
@@ -0,0 +1,67 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+/// CATPIP outfall pipe, intake pipe, sewer and bubbler system
+const CATPIP_WATER: &[i64] = &[2, 3, 4, 5];
+
+/// PRODCT water and drinking water
+const PRODCT_WATER: &[i64] = &[3, 8];
+
+fn is_water_pipe(attrs: &Map<String, Value>) -> bool {
+    let catpip = attrs.get("CATPIP").map(int_list).unwrap_or_default();
+    let prodct = attrs.get("PRODCT").map(int_list).unwrap_or_default();
+
+    catpip.iter().any(|v| CATPIP_WATER.contains(v))
+        || prodct.iter().any(|v| PRODCT_WATER.contains(v))
+}
+
+/// Pipeline line colour, shared with PIPARE: grey for water pipes and sewers
+/// (S-52 PIPSOL06), magenta for oil, gas and other pipelines (PIPSOL05)
+pub(super) fn pipeline_colour(attrs: &Map<String, Value>) -> &'static str {
+    if is_water_pipe(attrs) {
+        "CHGRD"
+    } else {
+        "CHMGD"
+    }
+}
+
+/// Pipeline line style matching `pipeline_colour`, shared with PIPARE
+pub(super) fn pipeline_line_style(attrs: &Map<String, Value>) -> &'static str {
+    if is_water_pipe(attrs) {
+        "PIPSOL06"
+    } else {
+        "PIPSOL05"
+    }
+}
+
+fn pipsol_style(attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some(pipeline_colour(attrs).into()),
+        ls: Some(pipeline_line_style(attrs).into()),
+        ..Default::default()
+    }
+}
+
+pub const PIPSOL: LayerDef = LayerDef {
+    s57_name: "PIPSOL",
+    table: "pipsol",
+    columns: &[
+        ColumnDef::new("CATPIP", "catpip", ColType::IntList),
+        ColumnDef::new("PRODCT", "prodct", ColType::IntList),
+        ColumnDef::new("BURDEP", "burdep", ColType::Float),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34070,
+    display_priority: 4,
+    style_fn: Some(pipsol_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["CHMGD", "CHGRD"])
+        .with_line_width(1.5)
+        .with_line_dash(DASH)
+        .with_line_styles(&["PIPSOL05", "PIPSOL06"])],
+};
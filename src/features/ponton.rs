@@ -0,0 +1,37 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn ponton_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        ac: Some("CHBRN".into()),
+        lc: Some("CSTLN".into()),
+        ..Default::default()
+    }
+}
+
+pub const PONTON: LayerDef = LayerDef {
+    s57_name: "PONTON",
+    table: "ponton",
+    columns: &[
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22010,
+    display_priority: 4,
+    style_fn: Some(ponton_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHBRN"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.0)
+            .with_filter(r#"["!", ["in", ["get", "condtn"], ["literal", [1, 2]]]]"#),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH)
+            .with_filter(r#"["in", ["get", "condtn"], ["literal", [1, 2]]]"#),
+    ],
+};
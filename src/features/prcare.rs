@@ -0,0 +1,31 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn prcare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        sy: Some("PRCARE12".into()),
+        ..Default::default()
+    }
+}
+
+pub const PRCARE: LayerDef = LayerDef {
+    s57_name: "PRCARE",
+    table: "prcare",
+    columns: &[
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25010,
+    display_priority: 3,
+    style_fn: Some(prcare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
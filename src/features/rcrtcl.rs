@@ -0,0 +1,48 @@
+use serde_json::{Map, Value};
+
+use super::rectrc::track_symbol;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn rcrtcl_style(attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        sy: Some(track_symbol(attrs).into()),
+        ..Default::default()
+    }
+}
+
+pub const RCRTCL: LayerDef = LayerDef {
+    s57_name: "RCRTCL",
+    table: "rcrtcl",
+    columns: &[
+        ColumnDef::new("CATTRK", "cattrk", ColType::Int),
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("TRAFIC", "trafic", ColType::Int),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25020,
+    display_priority: 6,
+    style_fn: Some(rcrtcl_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(2.0)
+            .with_filter(r#"["==", ["get", "cattrk"], 1]"#),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(2.0)
+            .with_line_dash(DASH)
+            .with_filter(r#"["!=", ["get", "cattrk"], 1]"#),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+        StyleLayerDef::new("bearing", StyleLayerType::Text)
+            .with_text("bearing_label", 11.0)
+            .with_symbol_placement("line")
+            .with_text_offset(0.0, -1.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,39 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn rctlpt_style(attrs: &Map<String, Value>) -> StyleProps {
+    // Recommended direction arrow rotated by ORIENT, or the
+    // direction-not-defined symbol when ORIENT is missing
+    let symbol = if attrs.get("ORIENT").and_then(|v| v.as_f64()).is_some() {
+        "RCTLPT52"
+    } else {
+        "RTLDEF51"
+    };
+
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        sy: Some(symbol.into()),
+        ..Default::default()
+    }
+}
+
+pub const RCTLPT: LayerDef = LayerDef {
+    s57_name: "RCTLPT",
+    table: "rctlpt",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25010,
+    display_priority: 6,
+    style_fn: Some(rctlpt_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+    ],
+};
@@ -0,0 +1,68 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+/// CATTRK value for a track based on a system of fixed marks
+const CATTRK_FIXED_MARKS: i64 = 1;
+
+/// Recommended track symbol, shared with RCRTCL: one-way (TRAFIC 1-3) or
+/// two-way arrows, in the fixed-marks variant when CATTRK = 1. Without ORIENT
+/// the direction is undefined and RECDEF51 is used.
+pub(super) fn track_symbol(attrs: &Map<String, Value>) -> &'static str {
+    let has_orient = attrs.get("ORIENT").and_then(|v| v.as_f64()).is_some();
+    let trafic = attrs.get("TRAFIC").and_then(|v| v.as_i64());
+    let fixed_marks = attrs.get("CATTRK").and_then(|v| v.as_i64()) == Some(CATTRK_FIXED_MARKS);
+
+    match (has_orient, trafic, fixed_marks) {
+        (false, _, _) => "RECDEF51",
+        (true, Some(1..=3), true) => "RECTRC58",
+        (true, Some(1..=3), false) => "RECTRC57",
+        (true, _, true) => "RECTRC56",
+        (true, _, false) => "RECTRC55",
+    }
+}
+
+fn rectrc_style(attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("CHBLK".into()),
+        sy: Some(track_symbol(attrs).into()),
+        ..Default::default()
+    }
+}
+
+pub const RECTRC: LayerDef = LayerDef {
+    s57_name: "RECTRC",
+    table: "rectrc",
+    columns: &[
+        ColumnDef::new("CATTRK", "cattrk", ColType::Int),
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("TRAFIC", "trafic", ColType::Int),
+        ColumnDef::new("DRVAL1", "drval1", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 25020,
+    display_priority: 4,
+    style_fn: Some(rectrc_style),
+    style_layers: &[
+        // Solid when based on fixed marks, dashed otherwise
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_filter(r#"["==", ["get", "cattrk"], 1]"#),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH)
+            .with_filter(r#"["!=", ["get", "cattrk"], 1]"#),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+        StyleLayerDef::new("bearing", StyleLayerType::Text)
+            .with_text("bearing_label", 11.0)
+            .with_symbol_placement("line")
+            .with_text_offset(0.0, -1.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
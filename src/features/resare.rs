@@ -0,0 +1,112 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+/// RESTRN values for entry prohibited/restricted and area to be avoided
+const RESTRN_ENTRY: &[i64] = &[7, 8, 14];
+
+/// RESTRN values for anchoring prohibited/restricted
+const RESTRN_ANCHORING: &[i64] = &[1, 2];
+
+/// RESTRN values for fishing, trawling and dredging restrictions
+const RESTRN_FISHING: &[i64] = &[3, 4, 5, 6, 24];
+
+/// RESTRN values for other restrictions (construction, discharging, ...)
+const RESTRN_OTHER: &[i64] = &[13, 16, 17, 23, 25, 26, 27];
+
+/// CATREA values that call for caution (safety zones, military, ...)
+const CATREA_CAUTION: &[i64] = &[1, 8, 9, 12, 14, 18, 19, 21, 24, 25, 26];
+
+/// CATREA values that only carry information (nature reserves, ...)
+const CATREA_INFORMATION: &[i64] = &[4, 5, 6, 7, 10, 20, 22, 23];
+
+/// Restriction symbol and boundary line style (LS) per S-52 RESARE02.
+///
+/// The first matching RESTRN group picks the symbol family (ENTRES, ACHRES,
+/// FSHRES). The ...61 variant adds the caution mark for further
+/// restrictions or a cautionary CATREA, the ...71 variant the information
+/// mark for an informative CATREA. Without RESTRN, CATREA alone selects the
+/// cautionary or information area symbol. MIPARE, which has no CATREA, passes
+/// an empty list and gets the RESTRN part (S-52 RESCSP02).
+pub(super) fn restriction_symbology(
+    restrn: &[i64],
+    catrea: &[i64],
+) -> (&'static str, &'static str) {
+    let any_of = |values: &[i64], set: &[i64]| values.iter().any(|v| set.contains(v));
+    let caution = any_of(catrea, CATREA_CAUTION);
+    let information = any_of(catrea, CATREA_INFORMATION);
+
+    // Variant for a restriction family: ...61 when `further` restrictions
+    // apply or CATREA calls for caution, ...71 for informative CATREA
+    let variant = |further: &[i64], symbols: [&'static str; 3]| {
+        if any_of(restrn, further) || caution {
+            symbols[1]
+        } else if information {
+            symbols[2]
+        } else {
+            symbols[0]
+        }
+    };
+
+    if any_of(restrn, RESTRN_ENTRY) {
+        let further = [RESTRN_ANCHORING, RESTRN_FISHING, RESTRN_OTHER].concat();
+        let symbol = variant(&further, ["ENTRES51", "ENTRES61", "ENTRES71"]);
+        (symbol, "ENTRES51")
+    } else if any_of(restrn, RESTRN_ANCHORING) {
+        let further = [RESTRN_FISHING, RESTRN_OTHER].concat();
+        let symbol = variant(&further, ["ACHRES51", "ACHRES61", "ACHRES71"]);
+        (symbol, "ACHRES51")
+    } else if any_of(restrn, RESTRN_FISHING) {
+        let symbol = variant(RESTRN_OTHER, ["FSHRES51", "FSHRES61", "FSHRES71"]);
+        (symbol, "FSHRES51")
+    } else if any_of(restrn, RESTRN_OTHER) {
+        ("INFARE51", "CTYARE51")
+    } else if !restrn.is_empty() {
+        ("RSRDEF51", "CTYARE51")
+    } else if caution {
+        let symbol = if information { "CTYARE71" } else { "CTYARE51" };
+        (symbol, "CTYARE51")
+    } else if information {
+        ("INFARE51", "CTYARE51")
+    } else {
+        ("RSRDEF51", "RESARE51")
+    }
+}
+
+fn resare_style(attrs: &Map<String, Value>) -> StyleProps {
+    let restrn = attrs.get("RESTRN").map(int_list).unwrap_or_default();
+    let catrea = attrs.get("CATREA").map(int_list).unwrap_or_default();
+    let (symbol, line_style) = restriction_symbology(&restrn, &catrea);
+
+    StyleProps {
+        lc: Some("CHMGD".into()),
+        sy: Some(symbol.into()),
+        ls: Some(line_style.into()),
+        ..Default::default()
+    }
+}
+
+pub const RESARE: LayerDef = LayerDef {
+    s57_name: "RESARE",
+    table: "resare",
+    columns: &[
+        ColumnDef::new("RESTRN", "restrn", ColType::IntList),
+        ColumnDef::new("CATREA", "catrea", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 26010,
+    display_priority: 3,
+    style_fn: Some(resare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHMGD"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_line_styles(&["ENTRES51", "ACHRES51", "FSHRES51", "CTYARE51", "RESARE51"]),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
@@ -0,0 +1,39 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn sbdare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Seabed nature is shown as text (seabed_label); lines get a dashed grey line
+    StyleProps {
+        lc: Some("CHGRD".into()),
+        ..Default::default()
+    }
+}
+
+pub const SBDARE: LayerDef = LayerDef {
+    s57_name: "SBDARE",
+    table: "sbdare",
+    columns: &[
+        ColumnDef::new("NATSUR", "natsur", ColType::IntList),
+        ColumnDef::new("NATQUA", "natqua", ColType::IntList),
+        ColumnDef::new("COLOUR", "colour", ColType::IntList),
+        ColumnDef::new("WATLEV", "watlev", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34010,
+    display_priority: 2,
+    style_fn: Some(sbdare_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH)
+            .with_filter(r#"["==", ["geometry-type"], "LineString"]"#),
+        StyleLayerDef::new("nature", StyleLayerType::Text)
+            .with_text("seabed_label", 11.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
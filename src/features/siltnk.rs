@@ -0,0 +1,52 @@
+use serde_json::{Map, Value};
+
+use super::lndmrk::is_conspicuous;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn siltnk_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATSIL: 1 silo, 2 tank, 3 grain elevator, 4 water tower
+    let catsil = attrs.get("CATSIL").and_then(|v| v.as_i64());
+    let (plain, consp) = match catsil {
+        Some(2) => ("TNKCON02", "TNKCON12"),
+        Some(4) => ("TOWERS02", "TOWERS12"),
+        _ => ("SILBUI01", "SILBUI11"),
+    };
+    let conspicuous = is_conspicuous(attrs);
+
+    StyleProps {
+        ac: Some("CHBRN".into()),
+        lc: Some(if conspicuous { "CHBLK" } else { "LANDF" }.into()),
+        sy: Some(if conspicuous { consp } else { plain }.into()),
+        ..Default::default()
+    }
+}
+
+pub const SILTNK: LayerDef = LayerDef {
+    s57_name: "SILTNK",
+    table: "siltnk",
+    columns: &[
+        ColumnDef::new("CATSIL", "catsil", ColType::Int),
+        ColumnDef::new("PRODCT", "prodct", ColType::IntList),
+        ColumnDef::new("CONVIS", "convis", ColType::Int),
+        ColumnDef::new("HEIGHT", "height", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Standard,
+    viewing_group: 22220,
+    display_priority: 5,
+    style_fn: Some(siltnk_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["CHBRN"]),
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["LANDF", "CHBLK"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
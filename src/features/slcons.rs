@@ -0,0 +1,58 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn slcons_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // S-52 SLCONS03 line styles are selected by the style layer filters below:
+    // dashed when under construction/ruined or submerged/covering, heavy for
+    // wharves, plain otherwise
+    StyleProps {
+        lc: Some("CSTLN".into()),
+        ..Default::default()
+    }
+}
+
+pub const SLCONS: LayerDef = LayerDef {
+    s57_name: "SLCONS",
+    table: "slcons",
+    columns: &[
+        ColumnDef::new("CATSLC", "catslc", ColType::Int),
+        ColumnDef::new("CONDTN", "condtn", ColType::Int),
+        ColumnDef::new("WATLEV", "watlev", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 12410,
+    display_priority: 4,
+    style_fn: Some(slcons_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(2.0)
+            .with_filter(
+                r#"["!", ["any",
+                    ["in", ["get", "condtn"], ["literal", [1, 2]]],
+                    ["in", ["get", "watlev"], ["literal", [3, 4]]],
+                    ["in", ["get", "catslc"], ["literal", [6, 15, 16]]]]]"#,
+            ),
+        StyleLayerDef::new("line_wharf", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(4.0)
+            .with_filter(
+                r#"["all",
+                    ["!", ["in", ["get", "condtn"], ["literal", [1, 2]]]],
+                    ["!", ["in", ["get", "watlev"], ["literal", [3, 4]]]],
+                    ["in", ["get", "catslc"], ["literal", [6, 15, 16]]]]"#,
+            ),
+        StyleLayerDef::new("line_dashed", StyleLayerType::Line)
+            .with_colors(&["CSTLN"])
+            .with_line_width(1.5)
+            .with_line_dash(DASH)
+            .with_filter(
+                r#"["any",
+                    ["in", ["get", "condtn"], ["literal", [1, 2]]],
+                    ["in", ["get", "watlev"], ["literal", [3, 4]]]]"#,
+            ),
+    ],
+};
@@ -0,0 +1,41 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{DASH, StyleLayerDef, StyleLayerType};
+
+fn smcfac_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // S-52 uses the marina symbol for every small craft facility; CATSCF
+    // (visitors' berth, fuel, water, showers, ...) is stored for filtering
+    StyleProps {
+        lc: Some("CHBLK".into()),
+        sy: Some("SMCFAC02".into()),
+        ..Default::default()
+    }
+}
+
+pub const SMCFAC: LayerDef = LayerDef {
+    s57_name: "SMCFAC",
+    table: "smcfac",
+    columns: &[
+        ColumnDef::new("CATSCF", "catscf", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32410,
+    display_priority: 5,
+    style_fn: Some(smcfac_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHBLK"])
+            .with_line_width(1.0)
+            .with_line_dash(DASH),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,30 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn sndwav_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Points get the sand wave symbol, areas the repeating sand wave pattern
+    StyleProps {
+        sy: Some("SNDWAV02".into()),
+        ap: Some("SNDWAV01P".into()),
+        ..Default::default()
+    }
+}
+
+pub const SNDWAV: LayerDef = LayerDef {
+    s57_name: "SNDWAV",
+    table: "sndwav",
+    columns: &[
+        ColumnDef::new("VERLEN", "verlen", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34010,
+    display_priority: 2,
+    style_fn: Some(sndwav_style),
+    style_layers: &[
+        StyleLayerDef::new("pattern", StyleLayerType::Pattern),
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+    ],
+};
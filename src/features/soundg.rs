@@ -1,6 +1,6 @@
 use serde_json::{Map, Value};
 
-use crate::feature::{ColType, ColumnDef, LayerDef, StyleProps};
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
 use crate::style::{StyleLayerDef, StyleLayerType};
 
 fn soundg_style(attrs: &Map<String, Value>) -> StyleProps {
@@ -15,8 +15,7 @@ fn soundg_style(attrs: &Map<String, Value>) -> StyleProps {
 
     StyleProps {
         ac,
-        lc: None,
-        sy: None,
+        ..Default::default()
     }
 }
 
@@ -30,6 +29,9 @@ pub const SOUNDG: LayerDef = LayerDef {
         ColumnDef::new("QUASOU", "quasou", ColType::Int),
         ColumnDef::new("STATUS", "status", ColType::Int),
     ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33010,
+    display_priority: 6,
     style_fn: Some(soundg_style),
     style_layers: &[StyleLayerDef::new("text", StyleLayerType::Text)
         .with_text("depth_meters_whole", 16.0)
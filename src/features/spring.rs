@@ -0,0 +1,22 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn spring_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        sy: Some("SPRING02".into()),
+        ..Default::default()
+    }
+}
+
+pub const SPRING: LayerDef = LayerDef {
+    s57_name: "SPRING",
+    table: "spring",
+    columns: &[ColumnDef::new("OBJNAM", "objnam", ColType::Text)],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34010,
+    display_priority: 4,
+    style_fn: Some(spring_style),
+    style_layers: &[StyleLayerDef::new("icon", StyleLayerType::Icon)],
+};
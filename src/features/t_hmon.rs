@@ -0,0 +1,36 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+/// Tide station symbol, shared with T_NHMN and T_TIMS
+pub(super) fn tide_station_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        sy: Some("TIDEHT01".into()),
+        ..Default::default()
+    }
+}
+
+pub const T_HMON: LayerDef = LayerDef {
+    s57_name: "T_HMON",
+    table: "t_hmon",
+    columns: &[
+        ColumnDef::new("T_ACWL", "t_acwl", ColType::Int),
+        ColumnDef::new("T_MTOD", "t_mtod", ColType::Int),
+        ColumnDef::new("T_VAHC", "t_vahc", ColType::Text),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(tide_station_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,27 @@
+use super::t_hmon::tide_station_style;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+pub const T_NHMN: LayerDef = LayerDef {
+    s57_name: "T_NHMN",
+    table: "t_nhmn",
+    columns: &[
+        ColumnDef::new("T_ACWL", "t_acwl", ColType::Int),
+        ColumnDef::new("T_MTOD", "t_mtod", ColType::Int),
+        ColumnDef::new("T_THDF", "t_thdf", ColType::Text),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(tide_station_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,30 @@
+use super::t_hmon::tide_station_style;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::height_series;
+
+pub const T_TIMS: LayerDef = LayerDef {
+    s57_name: "T_TIMS",
+    table: "t_tims",
+    columns: &[
+        ColumnDef::new("T_TSVL", "t_tsvl", ColType::Json(height_series)),
+        ColumnDef::new("T_TINT", "t_tint", ColType::Int),
+        ColumnDef::new("T_HWLW", "t_hwlw", ColType::Text),
+        ColumnDef::new("TIMSTR", "timstr", ColType::Text),
+        ColumnDef::new("TIMEND", "timend", ColType::Text),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(tide_station_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
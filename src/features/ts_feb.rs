@@ -0,0 +1,42 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn ts_feb_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CAT_TS: 1 flood stream, 2 ebb stream, 3 other tidal flow
+    let symbol = match attrs.get("CAT_TS").and_then(|v| v.as_i64()) {
+        Some(1) => "FLDSTR01",
+        Some(2) => "EBBSTR01",
+        _ => "CURENT01",
+    };
+
+    StyleProps {
+        sy: Some(symbol.into()),
+        ..Default::default()
+    }
+}
+
+pub const TS_FEB: LayerDef = LayerDef {
+    s57_name: "TS_FEB",
+    table: "ts_feb",
+    columns: &[
+        ColumnDef::new("CAT_TS", "cat_ts", ColType::Int),
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("CURVEL", "curvel", ColType::Float),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(ts_feb_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+        StyleLayerDef::new("velocity", StyleLayerType::Text)
+            .with_text("curvel_label", 10.0)
+            .with_text_anchor("top")
+            .with_text_offset(0.0, 1.2)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
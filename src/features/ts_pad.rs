@@ -0,0 +1,35 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::stream_panel;
+
+/// Tidal stream station symbol, shared with TS_PNH and TS_TIS
+pub(super) fn tidal_stream_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        sy: Some("TIDSTR01".into()),
+        ..Default::default()
+    }
+}
+
+pub const TS_PAD: LayerDef = LayerDef {
+    s57_name: "TS_PAD",
+    table: "ts_pad",
+    columns: &[
+        ColumnDef::new("TS_TSP", "ts_tsp", ColType::Json(stream_panel)),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(tidal_stream_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,29 @@
+use super::ts_pad::tidal_stream_style;
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::stream_series;
+
+pub const TS_TIS: LayerDef = LayerDef {
+    s57_name: "TS_TIS",
+    table: "ts_tis",
+    columns: &[
+        ColumnDef::new("TS_TSV", "ts_tsv", ColType::Json(stream_series)),
+        ColumnDef::new("T_TINT", "t_tint", ColType::Int),
+        ColumnDef::new("TIMSTR", "timstr", ColType::Text),
+        ColumnDef::new("TIMEND", "timend", ColType::Text),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 33060,
+    display_priority: 6,
+    style_fn: Some(tidal_stream_style),
+    style_layers: &[
+        StyleLayerDef::new("icon", StyleLayerType::Icon),
+        StyleLayerDef::new("label", StyleLayerType::Text)
+            .with_text("objnam", 11.0)
+            .with_text_anchor("left")
+            .with_text_offset(1.2, 0.0)
+            .with_text_halo(1.5)
+            .with_text_halo_color("#FFFFFF"),
+    ],
+};
@@ -0,0 +1,27 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn tselne_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("TRFCF".into()),
+        ..Default::default()
+    }
+}
+
+pub const TSELNE: LayerDef = LayerDef {
+    s57_name: "TSELNE",
+    table: "tselne",
+    columns: &[
+        ColumnDef::new("CATTSS", "cattss", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 15010,
+    display_priority: 4,
+    style_fn: Some(tselne_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["TRFCF"])
+        .with_line_width(3.0)],
+};
@@ -0,0 +1,25 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn tsezne_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        ac: Some("TRFCF".into()),
+        ..Default::default()
+    }
+}
+
+pub const TSEZNE: LayerDef = LayerDef {
+    s57_name: "TSEZNE",
+    table: "tsezne",
+    columns: &[
+        ColumnDef::new("CATTSS", "cattss", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 15010,
+    display_priority: 3,
+    style_fn: Some(tsezne_style),
+    style_layers: &[StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["TRFCF"])],
+};
@@ -0,0 +1,36 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn tsslpt_style(attrs: &Map<String, Value>) -> StyleProps {
+    // One-way traffic direction arrow, rotated by ORIENT in the style;
+    // lanes without a charted direction get no arrow
+    let has_orient = attrs.get("ORIENT").and_then(|v| v.as_f64()).is_some();
+
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        sy: has_orient.then(|| "TSSLPT51".into()),
+        ..Default::default()
+    }
+}
+
+pub const TSSLPT: LayerDef = LayerDef {
+    s57_name: "TSSLPT",
+    table: "tsslpt",
+    columns: &[
+        ColumnDef::new("ORIENT", "orient", ColType::Float),
+        ColumnDef::new("CATTSS", "cattss", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 15010,
+    display_priority: 6,
+    style_fn: Some(tsslpt_style),
+    style_layers: &[
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["TRFCD"])
+            .with_line_width(1.0),
+        StyleLayerDef::new("icon", StyleLayerType::Icon).with_icon_rotation(),
+    ],
+};
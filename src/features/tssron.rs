@@ -0,0 +1,27 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn tssron_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        lc: Some("TRFCD".into()),
+        ..Default::default()
+    }
+}
+
+pub const TSSRON: LayerDef = LayerDef {
+    s57_name: "TSSRON",
+    table: "tssron",
+    columns: &[
+        ColumnDef::new("CATTSS", "cattss", ColType::Int),
+        ColumnDef::new("STATUS", "status", ColType::Int),
+    ],
+    display_category: DisplayCategory::Base,
+    viewing_group: 15010,
+    display_priority: 6,
+    style_fn: Some(tssron_style),
+    style_layers: &[StyleLayerDef::new("line", StyleLayerType::Line)
+        .with_colors(&["TRFCD"])
+        .with_line_width(1.0)],
+};
@@ -0,0 +1,33 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn unsare_style(_attrs: &Map<String, Value>) -> StyleProps {
+    StyleProps {
+        ac: Some("NODTA".into()),
+        lc: Some("CHGRD".into()),
+        ap: Some("NODATA03P".into()),
+        ..Default::default()
+    }
+}
+
+pub const UNSARE: LayerDef = LayerDef {
+    s57_name: "UNSARE",
+    table: "unsare",
+    columns: &[],
+    display_category: DisplayCategory::Base,
+    viewing_group: 11050,
+    display_priority: 0,
+    style_fn: Some(unsare_style),
+    style_layers: &[
+        StyleLayerDef::new("fill", StyleLayerType::Fill).with_colors(&["NODTA"]),
+        StyleLayerDef::new("pattern", StyleLayerType::Pattern),
+        // The boundary goes with other line work, so neighbouring depth
+        // areas (priority 1) don't paint over half of it
+        StyleLayerDef::new("line", StyleLayerType::Line)
+            .with_colors(&["CHGRD"])
+            .with_line_width(2.0)
+            .with_display_priority(4),
+    ],
+};
@@ -0,0 +1,34 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+use crate::util::int_list;
+
+fn vegatn_style(attrs: &Map<String, Value>) -> StyleProps {
+    // CATVEG: 7 mangroves, 21 mangrove tree; everything else as woodland
+    let catveg = attrs.get("CATVEG").map(int_list).unwrap_or_default();
+    let pattern = if catveg.iter().any(|cat| matches!(cat, 7 | 21)) {
+        "VEGATN04P"
+    } else {
+        "VEGATN03P"
+    };
+
+    StyleProps {
+        ap: Some(pattern.into()),
+        ..Default::default()
+    }
+}
+
+pub const VEGATN: LayerDef = LayerDef {
+    s57_name: "VEGATN",
+    table: "vegatn",
+    columns: &[
+        ColumnDef::new("CATVEG", "catveg", ColType::IntList),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 32030,
+    display_priority: 2,
+    style_fn: Some(vegatn_style),
+    style_layers: &[StyleLayerDef::new("pattern", StyleLayerType::Pattern)],
+};
@@ -0,0 +1,26 @@
+use serde_json::{Map, Value};
+
+use crate::feature::{ColType, ColumnDef, DisplayCategory, LayerDef, StyleProps};
+use crate::style::{StyleLayerDef, StyleLayerType};
+
+fn wedklp_style(_attrs: &Map<String, Value>) -> StyleProps {
+    // Same symbol for kelp, sea weed, sea grass and sargasso (CATWED)
+    StyleProps {
+        sy: Some("WEDKLP03".into()),
+        ..Default::default()
+    }
+}
+
+pub const WEDKLP: LayerDef = LayerDef {
+    s57_name: "WEDKLP",
+    table: "wedklp",
+    columns: &[
+        ColumnDef::new("CATWED", "catwed", ColType::Int),
+        ColumnDef::new("OBJNAM", "objnam", ColType::Text),
+    ],
+    display_category: DisplayCategory::Other,
+    viewing_group: 34010,
+    display_priority: 4,
+    style_fn: Some(wedklp_style),
+    style_layers: &[StyleLayerDef::new("icon", StyleLayerType::Icon)],
+};
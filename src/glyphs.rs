@@ -0,0 +1,322 @@
+use std::fs;
+use std::path::Path;
+
+use log::{info, warn};
+use ttf_parser::{Face, GlyphId, OutlineBuilder, name_id};
+
+/// Font size glyphs are rendered at; MapLibre scales SDFs from this 24px em
+const GLYPH_SIZE: f32 = 24.0;
+
+/// SDF border around each glyph bitmap, in pixels
+const GLYPH_BUFFER: i32 = 3;
+
+/// Distance (px) covered by the full 0-255 SDF range
+const SDF_RADIUS: f32 = 8.0;
+
+/// Fraction of the SDF range that lies outside the glyph edge
+const SDF_CUTOFF: f32 = 0.25;
+
+/// Number of codepoints per PBF range file (0-255, 256-511, ...)
+const RANGE_SIZE: u32 = 256;
+
+/// Curves are flattened into this many line segments
+const CURVE_STEPS: usize = 8;
+
+/// Line segment between two points in pixel space
+type Segment = ((f32, f32), (f32, f32));
+
+/// Glyph outline flattened into line segments, in pixel space (y up)
+#[derive(Default)]
+struct FlatOutline {
+    segments: Vec<Segment>,
+    start: (f32, f32),
+    current: (f32, f32),
+    scale: f32,
+}
+
+impl FlatOutline {
+    fn push(&mut self, to: (f32, f32)) {
+        if to != self.current {
+            self.segments.push((self.current, to));
+        }
+        self.current = to;
+    }
+}
+
+impl OutlineBuilder for FlatOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = (x * self.scale, y * self.scale);
+        self.current = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push((x * self.scale, y * self.scale));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current;
+        let (x1, y1) = (x1 * self.scale, y1 * self.scale);
+        let (x, y) = (x * self.scale, y * self.scale);
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let mt = 1.0 - t;
+            self.push((
+                mt * mt * x0 + 2.0 * mt * t * x1 + t * t * x,
+                mt * mt * y0 + 2.0 * mt * t * y1 + t * t * y,
+            ));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current;
+        let (x1, y1) = (x1 * self.scale, y1 * self.scale);
+        let (x2, y2) = (x2 * self.scale, y2 * self.scale);
+        let (x, y) = (x * self.scale, y * self.scale);
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let mt = 1.0 - t;
+            self.push((
+                mt * mt * mt * x0 + 3.0 * mt * mt * t * x1 + 3.0 * mt * t * t * x2 + t * t * t * x,
+                mt * mt * mt * y0 + 3.0 * mt * mt * t * y1 + 3.0 * mt * t * t * y2 + t * t * t * y,
+            ));
+        }
+    }
+
+    fn close(&mut self) {
+        let start = self.start;
+        self.push(start);
+    }
+}
+
+/// Rendered SDF glyph with metrics in the layout MapLibre expects
+struct SdfGlyph {
+    id: u32,
+    bitmap: Vec<u8>,
+    width: u32,
+    height: u32,
+    left: i32,
+    top: i32,
+    advance: u32,
+}
+
+/// Distance from point `p` to segment `a`-`b`
+fn segment_distance(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (cx, cy) = (a.0 + t * dx, a.1 + t * dy);
+    ((p.0 - cx).powi(2) + (p.1 - cy).powi(2)).sqrt()
+}
+
+/// Non-zero winding test against the flattened outline
+fn is_inside(p: (f32, f32), segments: &[Segment]) -> bool {
+    let mut winding = 0;
+    for &(a, b) in segments {
+        if a.1 <= p.1 {
+            if b.1 > p.1 && (b.0 - a.0) * (p.1 - a.1) - (p.0 - a.0) * (b.1 - a.1) > 0.0 {
+                winding += 1;
+            }
+        } else if b.1 <= p.1 && (b.0 - a.0) * (p.1 - a.1) - (p.0 - a.0) * (b.1 - a.1) < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding != 0
+}
+
+/// Render one codepoint as a signed distance field.
+/// Returns None when the font has no glyph for the codepoint.
+fn render_sdf_glyph(face: &Face, codepoint: u32) -> Option<SdfGlyph> {
+    let ch = char::from_u32(codepoint)?;
+    let glyph_id: GlyphId = face.glyph_index(ch)?;
+
+    let scale = GLYPH_SIZE / face.units_per_em() as f32;
+    let advance = (face.glyph_hor_advance(glyph_id).unwrap_or(0) as f32 * scale).round() as u32;
+    let ascender = (face.ascender() as f32 * scale).round() as i32;
+
+    let mut outline = FlatOutline {
+        scale,
+        ..Default::default()
+    };
+    let Some(bbox) = face.outline_glyph(glyph_id, &mut outline) else {
+        // Whitespace and other glyphs without an outline only carry an advance
+        return Some(SdfGlyph {
+            id: codepoint,
+            bitmap: Vec::new(),
+            width: 0,
+            height: 0,
+            left: 0,
+            top: -ascender,
+            advance,
+        });
+    };
+
+    let x_min = (bbox.x_min as f32 * scale).floor() as i32;
+    let y_min = (bbox.y_min as f32 * scale).floor() as i32;
+    let x_max = (bbox.x_max as f32 * scale).ceil() as i32;
+    let y_max = (bbox.y_max as f32 * scale).ceil() as i32;
+    let width = (x_max - x_min).max(0) as u32;
+    let height = (y_max - y_min).max(0) as u32;
+
+    let buffered_w = width as i32 + 2 * GLYPH_BUFFER;
+    let buffered_h = height as i32 + 2 * GLYPH_BUFFER;
+    let mut bitmap = Vec::with_capacity((buffered_w * buffered_h) as usize);
+
+    for row in 0..buffered_h {
+        for col in 0..buffered_w {
+            // Pixel centre in outline space (rows run top-down, outline y runs up)
+            let p = (
+                (x_min - GLYPH_BUFFER + col) as f32 + 0.5,
+                (y_max + GLYPH_BUFFER - row) as f32 - 0.5,
+            );
+            let distance = outline
+                .segments
+                .iter()
+                .map(|&(a, b)| segment_distance(p, a, b))
+                .fold(f32::MAX, f32::min);
+            let signed = if is_inside(p, &outline.segments) {
+                -distance
+            } else {
+                distance
+            };
+            let value = 255.0 - 255.0 * (signed / SDF_RADIUS + SDF_CUTOFF);
+            bitmap.push(value.clamp(0.0, 255.0) as u8);
+        }
+    }
+
+    Some(SdfGlyph {
+        id: codepoint,
+        bitmap,
+        width,
+        height,
+        left: x_min,
+        top: y_max - ascender,
+        advance,
+    })
+}
+
+/// Append a protobuf varint
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Append a varint-encoded field
+fn write_varint_field(buf: &mut Vec<u8>, field: u32, value: u64) {
+    write_varint(buf, u64::from(field << 3));
+    write_varint(buf, value);
+}
+
+/// Append a zigzag-encoded `sint32` field
+fn write_sint32_field(buf: &mut Vec<u8>, field: u32, value: i32) {
+    write_varint_field(buf, field, u64::from(((value << 1) ^ (value >> 31)) as u32));
+}
+
+/// Append a length-delimited field (bytes, string or embedded message)
+fn write_bytes_field(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_varint(buf, u64::from((field << 3) | 2));
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// Encode one range as a `glyphs` protobuf message (Mapbox glyphs.proto)
+fn encode_glyph_range(fontstack: &str, range: &str, glyphs: &[SdfGlyph]) -> Vec<u8> {
+    let mut stack = Vec::new();
+    write_bytes_field(&mut stack, 1, fontstack.as_bytes());
+    write_bytes_field(&mut stack, 2, range.as_bytes());
+
+    for glyph in glyphs {
+        let mut msg = Vec::new();
+        write_varint_field(&mut msg, 1, u64::from(glyph.id));
+        if !glyph.bitmap.is_empty() {
+            write_bytes_field(&mut msg, 2, &glyph.bitmap);
+        }
+        write_varint_field(&mut msg, 3, u64::from(glyph.width));
+        write_varint_field(&mut msg, 4, u64::from(glyph.height));
+        write_sint32_field(&mut msg, 5, glyph.left);
+        write_sint32_field(&mut msg, 6, glyph.top);
+        write_varint_field(&mut msg, 7, u64::from(glyph.advance));
+        write_bytes_field(&mut stack, 3, &msg);
+    }
+
+    let mut out = Vec::new();
+    write_bytes_field(&mut out, 1, &stack);
+    out
+}
+
+/// Fontstack name as "{family} {style}" (e.g. "Roboto Bold"), preferring the
+/// typographic names so weights like Medium aren't folded into the family.
+fn fontstack_name(face: &Face) -> Option<String> {
+    let find = |id: u16| {
+        face.names()
+            .into_iter()
+            .find(|name| name.name_id == id && name.is_unicode())
+            .and_then(|name| name.to_string())
+    };
+
+    let family = find(name_id::TYPOGRAPHIC_FAMILY).or_else(|| find(name_id::FAMILY))?;
+    let style = find(name_id::TYPOGRAPHIC_SUBFAMILY).or_else(|| find(name_id::SUBFAMILY));
+
+    Some(match style {
+        Some(style) => format!("{} {}", family, style),
+        None => family,
+    })
+}
+
+/// Render SDF glyph ranges for every TTF in `font_dir` into
+/// `{output_dir}/{fontstack}/{start}-{end}.pbf`.
+pub fn generate_glyphs(font_dir: &Path, output_dir: &Path) {
+    let mut font_paths: Vec<_> = fs::read_dir(font_dir)
+        .expect("Failed to read font directory")
+        .map(|entry| entry.expect("Failed to read directory entry").path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("ttf"))
+        })
+        .collect();
+    font_paths.sort();
+
+    for path in font_paths {
+        let data = fs::read(&path).expect("Failed to read font file");
+        let face = match Face::parse(&data, 0) {
+            Ok(face) => face,
+            Err(e) => {
+                warn!("Failed to parse font {:?}: {}", path, e);
+                continue;
+            }
+        };
+        let Some(fontstack) = fontstack_name(&face) else {
+            warn!("Font {:?} has no family name, skipping", path);
+            continue;
+        };
+
+        let stack_dir = output_dir.join(&fontstack);
+        fs::create_dir_all(&stack_dir).expect("Failed to create fontstack output directory");
+
+        let mut glyph_count = 0;
+        for start in (0..=u32::from(u16::MAX)).step_by(RANGE_SIZE as usize) {
+            let end = start + RANGE_SIZE - 1;
+            let glyphs: Vec<SdfGlyph> = (start..=end)
+                .filter_map(|cp| render_sdf_glyph(&face, cp))
+                .collect();
+            glyph_count += glyphs.len();
+
+            // Empty ranges are still written so clients get a valid (empty) PBF, not a 404
+            let range = format!("{}-{}", start, end);
+            let pbf = encode_glyph_range(&fontstack, &range, &glyphs);
+            fs::write(stack_dir.join(format!("{}.pbf", range)), pbf)
+                .expect("Failed to write glyph range");
+        }
+
+        info!(
+            "Generated {} glyphs for '{}' from {:?}",
+            glyph_count, fontstack, path
+        );
+    }
+}
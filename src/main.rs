@@ -1,7 +1,11 @@
+mod attributes;
 mod colors;
 mod db;
+mod documents;
 mod feature;
 mod features;
+mod glyphs;
+mod route;
 mod s57;
 mod sprite;
 mod style;
@@ -12,9 +16,10 @@ use gdal::Dataset;
 use gdal::version::VersionInfo;
 use indicatif::{ProgressBar, ProgressStyle};
 use log::{debug, error, info, warn};
+use sqlx::Acquire;
 
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::Semaphore;
 
@@ -41,7 +46,7 @@ fn init_gdal() {
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[arg(long, required_unless_present_any = ["style_output", "sprites_output"])]
+    #[arg(long, required_unless_present_any = ["style_output", "sprites_output", "glyphs_output", "route"])]
     input_dir: Option<PathBuf>,
 
     #[arg(long, default_value = "info")]
@@ -51,18 +56,64 @@ struct Args {
     #[arg(long)]
     style_output: Option<PathBuf>,
 
-    /// Generate themed sprite SVGs into this directory and exit
+    /// Generate themed sprite SVGs and packed PNG sprite sheets into this directory and exit
     #[arg(long)]
     sprites_output: Option<PathBuf>,
 
+    /// Render SDF glyph PBF ranges for the fonts in `fonts/` into this directory and exit
+    #[arg(long)]
+    glyphs_output: Option<PathBuf>,
+
     /// Color theme for style generation
     #[arg(long, default_value = "day")]
     theme: String,
 
+    /// Display categories shown by the generated style: base, standard or all
+    #[arg(
+        long,
+        default_value = "all",
+        value_parser = clap::builder::PossibleValuesParser::new(
+            style::DISPLAY_MODES.iter().map(|(name, _)| *name)
+        )
+    )]
+    display_category: String,
+
+    /// Show cell coverage outlines and labels in the generated style (a client can toggle them later)
+    #[arg(long, default_value_t = false)]
+    chart_boundaries: bool,
+
     /// Vector tile source URL for style JSON
     #[arg(long, default_value = "http://localhost:3000")]
     tile_source_url: String,
 
+    /// Base URL of packed sprite sheets for style JSON (defaults to Martin's `{tile-source-url}/sprite`)
+    #[arg(long)]
+    sprite_base_url: Option<String>,
+
+    /// Base URL of static glyph PBFs for style JSON (defaults to Martin's `{tile-source-url}/font`)
+    #[arg(long)]
+    glyphs_base_url: Option<String>,
+
+    /// Check a route (GeoJSON LineString or RTZ file) for hazards, print them as JSON and exit
+    #[arg(long)]
+    route: Option<PathBuf>,
+
+    /// Vessel draft in metres for --route
+    #[arg(long, default_value_t = 2.0)]
+    draft: f64,
+
+    /// Under-keel safety margin in metres added to the draft for --route
+    #[arg(long, default_value_t = 1.0)]
+    safety_margin: f64,
+
+    /// Half-width in metres of the corridor checked around each route leg
+    #[arg(long, default_value_t = 185.2)]
+    corridor_m: f64,
+
+    /// Mark cells as overscale in enc_mvt once a tile zoom shows them at this factor times their compilation scale (tiles can override it with `?overscale_factor=`)
+    #[arg(long, default_value_t = 2.0)]
+    overscale_factor: f64,
+
     /// Force reimport of ENCs even if already present with same edition/update
     #[arg(long, default_value_t = false)]
     force_reimport: bool,
@@ -135,6 +186,8 @@ async fn process_s57_file(
 
     // Upsert enc_catalog
     db::upsert_enc_catalog(&mut tx, &enc_name, &metadata, coverage_geojson.as_deref()).await?;
+    db::clear_place_names(&mut tx, &enc_name).await?;
+    db::clear_enc_documents(&mut tx, &enc_name).await?;
 
     // Process each feature layer
     let ctx = feature::ChartContext {
@@ -163,6 +216,40 @@ async fn process_s57_file(
         }
     }
 
+    // Text and picture files referenced by TXTDSC/NTXTDS/PICREP sit beside the .000 file
+    let cell_dir = s57_path.parent().unwrap_or(Path::new("."));
+    for (attribute, filename) in s57::extract_document_references(&dataset) {
+        match documents::load_document(cell_dir, attribute, &filename, &metadata) {
+            Some(Ok(document)) => {
+                let mut savepoint = tx.begin().await?;
+                match db::upsert_enc_document(&mut savepoint, &enc_name, &document).await {
+                    Ok(()) => savepoint.commit().await?,
+                    Err(e) => {
+                        savepoint.rollback().await?;
+                        warn!(
+                            "{}: failed to store {} {}: {}",
+                            enc_name, attribute, filename, e
+                        );
+                    }
+                }
+            }
+            Some(Err(e)) => warn!(
+                "{}: failed to read {} {}: {}",
+                enc_name, attribute, filename, e
+            ),
+            None => warn!("{}: {} file {} not found", enc_name, attribute, filename),
+        }
+    }
+
+    let mut savepoint = tx.begin().await?;
+    match db::update_catalog_zoc_fractions(&mut savepoint, &enc_name).await {
+        Ok(()) => savepoint.commit().await?,
+        Err(e) => {
+            savepoint.rollback().await?;
+            warn!("Failed to summarise data quality for {}: {}", enc_name, e);
+        }
+    }
+
     tx.commit().await?;
 
     // If M_COVR was missing, update coverage from convex hull of inserted features
@@ -227,20 +314,40 @@ async fn main() {
         return;
     }
 
+    // Glyph generation mode — no DB or GDAL needed
+    if let Some(glyphs_output) = &args.glyphs_output {
+        let font_source = PathBuf::from("fonts");
+        glyphs::generate_glyphs(&font_source, glyphs_output);
+        info!("Generated glyph ranges in {:?}", glyphs_output);
+        return;
+    }
+
     // Style JSON generation mode — no DB or GDAL needed
     if let Some(style_path) = &args.style_output {
         let layers = features::all_layers();
-        let json = style::generate_style_json(layers, &args.theme, &args.tile_source_url);
+        let sprite_base_url = args
+            .sprite_base_url
+            .clone()
+            .unwrap_or_else(|| format!("{}/sprite", args.tile_source_url));
+        // Martin serves glyphs without an extension; static PBF files need one
+        let glyphs_url = match &args.glyphs_base_url {
+            Some(base) => format!("{}/{{fontstack}}/{{range}}.pbf", base),
+            None => format!("{}/font/{{fontstack}}/{{range}}", args.tile_source_url),
+        };
+        let json = style::generate_style_json(
+            layers,
+            &args.theme,
+            &args.tile_source_url,
+            &sprite_base_url,
+            &glyphs_url,
+            &args.display_category,
+            args.chart_boundaries,
+        );
         std::fs::write(style_path, json).expect("Failed to write style JSON");
         info!("Wrote style JSON to {:?}", style_path);
         return;
     }
 
-    let input_dir = args.input_dir.as_ref().expect("--input-dir is required");
-
-    info!("GDAL version: {}", VersionInfo::version_summary());
-    info!("Input directory: {:?}", input_dir);
-
     let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
     info!("Using database URL: {}", db_url);
     info!(
@@ -250,7 +357,42 @@ async fn main() {
     let pool = db::create_pool(&db_url, args.max_connections, args.min_connections).await;
 
     db::run_migrations(&pool).await;
-    db::ensure_layer_tables(&pool).await;
+    db::ensure_layer_tables(&pool, args.overscale_factor).await;
+
+    // Route check mode — queries already-imported data, no ENC input needed
+    if let Some(route_path) = &args.route {
+        let route_geojson = route::load_route_geojson(route_path)
+            .unwrap_or_else(|e| panic!("Failed to load route {:?}: {}", route_path, e));
+        info!(
+            "Checking route {:?}: draft={}m, safety margin={}m, corridor={}m",
+            route_path, args.draft, args.safety_margin, args.corridor_m
+        );
+        let hazards = db::check_route(
+            &pool,
+            &route_geojson,
+            args.draft,
+            args.safety_margin,
+            args.corridor_m,
+        )
+        .await
+        .expect("Failed to check route");
+        let count = hazards.as_array().map_or(0, |h| h.len());
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&hazards).expect("Failed to serialize hazards")
+        );
+        if count > 0 {
+            warn!("Route check found {} hazards", count);
+        } else {
+            info!("Route check found no hazards");
+        }
+        return;
+    }
+
+    let input_dir = args.input_dir.as_ref().expect("--input-dir is required");
+
+    info!("GDAL version: {}", VersionInfo::version_summary());
+    info!("Input directory: {:?}", input_dir);
 
     let layers = features::all_layers();
     let enc_paths = s57::find_enc_directories(input_dir);
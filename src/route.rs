@@ -0,0 +1,77 @@
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+
+/// Load a route file as a GeoJSON LineString geometry string.
+///
+/// Accepts GeoJSON (a LineString geometry, or a Feature / FeatureCollection whose
+/// first LineString is used) and RTZ (IEC 61174 route exchange XML, `.rtz`).
+pub fn load_route_geojson(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+
+    let is_rtz = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("rtz"));
+
+    let line = if is_rtz {
+        rtz_to_linestring(&content)?
+    } else {
+        let geojson: Value = serde_json::from_str(&content)?;
+        find_linestring(&geojson).ok_or("No LineString found in GeoJSON route")?
+    };
+
+    Ok(line.to_string())
+}
+
+/// Find the first LineString geometry in a GeoJSON geometry, Feature or FeatureCollection
+fn find_linestring(geojson: &Value) -> Option<Value> {
+    match geojson.get("type").and_then(|t| t.as_str()) {
+        Some("LineString") => Some(geojson.clone()),
+        Some("Feature") => geojson.get("geometry").and_then(find_linestring),
+        Some("FeatureCollection") => geojson
+            .get("features")?
+            .as_array()?
+            .iter()
+            .find_map(find_linestring),
+        _ => None,
+    }
+}
+
+/// Convert RTZ waypoints (`<waypoint><position lat=".." lon=".."/></waypoint>`)
+/// into a GeoJSON LineString, in waypoint order.
+fn rtz_to_linestring(xml: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let doc = roxmltree::Document::parse(xml)?;
+
+    let mut coordinates: Vec<[f64; 2]> = Vec::new();
+    for waypoint in doc
+        .descendants()
+        .filter(|n| n.tag_name().name() == "waypoint")
+    {
+        let Some(position) = waypoint
+            .children()
+            .find(|n| n.tag_name().name() == "position")
+        else {
+            continue;
+        };
+
+        let lat: f64 = position
+            .attribute("lat")
+            .ok_or("RTZ position missing lat")?
+            .parse()?;
+        let lon: f64 = position
+            .attribute("lon")
+            .ok_or("RTZ position missing lon")?
+            .parse()?;
+        coordinates.push([lon, lat]);
+    }
+
+    if coordinates.len() < 2 {
+        return Err("RTZ route needs at least two waypoints".into());
+    }
+
+    Ok(json!({
+        "type": "LineString",
+        "coordinates": coordinates,
+    }))
+}
@@ -1,15 +1,24 @@
 use gdal::Dataset;
 use gdal::vector::LayerAccess;
 use log::{debug, error, warn};
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::feature::DOCUMENT_ATTRIBUTES;
+
 /// S-57 metadata extracted from DSID layer
 #[derive(Debug, Clone)]
 pub struct S57Metadata {
     pub edition: Option<i32>,
     pub update_number: i32,
     pub compilation_scale: i32,
+    /// DSID_INTU intended usage (navigational purpose), 1 overview to 6 berthing
+    pub usage_band: Option<i32>,
+    /// DSSI_AALL lexical level of ATTF text, including TXTDSC files
+    pub attf_lexical_level: i32,
+    /// DSSI_NALL lexical level of NATF text, including NTXTDS files
+    pub natf_lexical_level: i32,
 }
 
 /// Extract S-57 metadata from DSID layer, including DSPM_CSCL compilation scale
@@ -21,6 +30,9 @@ pub fn extract_metadata(dataset: &Dataset) -> S57Metadata {
                     let mut edition = None;
                     let mut update_number = 0;
                     let mut compilation_scale = 0;
+                    let mut usage_band = None;
+                    let mut attf_lexical_level = 1;
+                    let mut natf_lexical_level = 2;
 
                     for (field_name, field_value_opt) in feature.fields() {
                         if let Some(field_value) = field_value_opt {
@@ -30,6 +42,13 @@ pub fn extract_metadata(dataset: &Dataset) -> S57Metadata {
                                 "DSPM_CSCL" => {
                                     compilation_scale = field_value.into_int().unwrap_or(0)
                                 }
+                                "DSID_INTU" => usage_band = field_value.into_int(),
+                                "DSSI_AALL" => {
+                                    attf_lexical_level = field_value.into_int().unwrap_or(1)
+                                }
+                                "DSSI_NALL" => {
+                                    natf_lexical_level = field_value.into_int().unwrap_or(2)
+                                }
                                 _ => {}
                             }
                         }
@@ -39,6 +58,9 @@ pub fn extract_metadata(dataset: &Dataset) -> S57Metadata {
                         edition,
                         update_number,
                         compilation_scale,
+                        usage_band,
+                        attf_lexical_level,
+                        natf_lexical_level,
                     };
                 }
             }
@@ -49,7 +71,39 @@ pub fn extract_metadata(dataset: &Dataset) -> S57Metadata {
         edition: None,
         update_number: 0,
         compilation_scale: 0,
+        usage_band: None,
+        attf_lexical_level: 1,
+        natf_lexical_level: 2,
+    }
+}
+
+/// Collect the external files referenced by TXTDSC, NTXTDS and PICREP across
+/// every object class in the cell, as `(attribute, filename)` pairs.
+pub fn extract_document_references(dataset: &Dataset) -> BTreeSet<(&'static str, String)> {
+    let mut references = BTreeSet::new();
+
+    for layer_idx in 0..dataset.layer_count() {
+        let Ok(mut layer) = dataset.layer(layer_idx) else {
+            continue;
+        };
+
+        for feature in layer.features() {
+            for &attribute in DOCUMENT_ATTRIBUTES {
+                let filename = feature
+                    .field_index(attribute)
+                    .ok()
+                    .and_then(|idx| feature.field(idx).ok())
+                    .flatten()
+                    .and_then(|v| v.into_string());
+
+                if let Some(filename) = filename.filter(|f| !f.trim().is_empty()) {
+                    references.insert((attribute, filename.trim().to_string()));
+                }
+            }
+        }
     }
+
+    references
 }
 
 /// Extract coverage polygon from M_COVR layer (CATCOV=1 features).
@@ -1,10 +1,27 @@
 use std::fs;
 use std::path::Path;
 
-use log::info;
+use log::{info, warn};
+use resvg::tiny_skia::{Pixmap, PixmapPaint, Transform};
+use resvg::usvg;
+use serde_json::{Map, Value, json};
 
 use crate::style::{THEME_NAMES, color_map_for_theme};
 
+/// Pixel ratios rendered into sprite sheets: `{theme}.png` and `{theme}@2x.png`
+const PIXEL_RATIOS: &[u32] = &[1, 2];
+
+/// Transparent gap between packed images so neighbours don't bleed when sampled
+const SHEET_PADDING: u32 = 1;
+
+/// One rasterised symbol, padded so its S-52 pivot point sits at the image centre
+struct SpriteImage {
+    name: String,
+    pixmap: Pixmap,
+    /// Offset of the pivot point from the unpadded symbol's centre, in pixels
+    pivot_offset: (f32, f32),
+}
+
 /// Generate CSS string for a given theme, matching njord's create_sheet.py output.
 fn generate_css(theme_name: &str) -> String {
     let colors = color_map_for_theme(theme_name);
@@ -41,31 +58,298 @@ fn generate_css(theme_name: &str) -> String {
     css
 }
 
-/// Generate themed sprite directories with CSS inlined into each SVG.
+/// Parse the root viewBox as (x, y, width, height). Returns None when it is
+/// missing, malformed or has no area.
+fn view_box(doc: &roxmltree::Document) -> Option<[f32; 4]> {
+    let values: Vec<f32> = doc
+        .root_element()
+        .attribute("viewBox")?
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let [x, y, w, h] = values[..] else {
+        return None;
+    };
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+    Some([x, y, w, h])
+}
+
+/// Read the `.pivotPoint` circle position as a fraction of the SVG viewBox.
+/// Returns None when the symbol has no pivot point or no usable viewBox.
+fn pivot_fraction(svg: &str) -> Option<(f32, f32)> {
+    let doc = roxmltree::Document::parse(svg).ok()?;
+    let root = doc.root_element();
+
+    let [vb_x, vb_y, vb_w, vb_h] = view_box(&doc)?;
+
+    let pivot = root.descendants().find(|n| {
+        n.attribute("class")
+            .is_some_and(|c| c.split_whitespace().any(|class| class == "pivotPoint"))
+    })?;
+    let cx: f32 = pivot.attribute("cx")?.parse().ok()?;
+    let cy: f32 = pivot.attribute("cy")?.parse().ok()?;
+
+    Some(((cx - vb_x) / vb_w, (cy - vb_y) / vb_h))
+}
+
+/// Read the `.svgBox` rectangle and the viewBox, both as (x, y, width, height)
+/// in viewBox units. Pattern symbols use the svgBox as their repeat cell.
+fn svg_box(svg: &str) -> Option<([f32; 4], [f32; 4])> {
+    let doc = roxmltree::Document::parse(svg).ok()?;
+    let root = doc.root_element();
+
+    let view_box = view_box(&doc)?;
+
+    let rect = root.descendants().find(|n| {
+        n.attribute("class")
+            .is_some_and(|c| c.split_whitespace().any(|class| class == "svgBox"))
+    })?;
+    let x: f32 = rect.attribute("x")?.parse().ok()?;
+    let y: f32 = rect.attribute("y")?.parse().ok()?;
+    let w: f32 = rect.attribute("width")?.parse().ok()?;
+    let h: f32 = rect.attribute("height")?.parse().ok()?;
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+
+    Some(([x, y, w, h], view_box))
+}
+
+/// S-52 area patterns are named with a trailing `P` (e.g. FOULAR01P)
+fn is_pattern(name: &str) -> bool {
+    name.ends_with('P')
+}
+
+/// Rasterise a pattern symbol as one repeat cell of its fill.
+///
+/// The image covers exactly the `.svgBox`, which includes the pattern's
+/// spacing, stretched to whole pixels so `fill-pattern` tiles it without
+/// seams. Patterns have no pivot, so no centring padding is added.
+fn rasterise_pattern(name: &str, svg: &str, pixel_ratio: u32) -> Option<SpriteImage> {
+    let tree = match usvg::Tree::from_str(svg, &usvg::Options::default()) {
+        Ok(tree) => tree,
+        Err(e) => {
+            warn!("Failed to parse SVG for {}: {}", name, e);
+            return None;
+        }
+    };
+
+    let Some(([box_x, box_y, box_w, box_h], [vb_x, vb_y, vb_w, vb_h])) = svg_box(svg) else {
+        warn!("Pattern {} has no svgBox, rendering as a symbol", name);
+        return rasterise_symbol(name, svg, pixel_ratio);
+    };
+
+    // Pixels per viewBox unit at a pixel ratio of 1
+    let (unit_x, unit_y) = (tree.size().width() / vb_w, tree.size().height() / vb_h);
+
+    let ratio = pixel_ratio as f32;
+    let width = (box_w * unit_x * ratio).round().max(1.0);
+    let height = (box_h * unit_y * ratio).round().max(1.0);
+    let mut pixmap = Pixmap::new(width as u32, height as u32)?;
+
+    let transform = Transform::from_translate(-(box_x - vb_x) * unit_x, -(box_y - vb_y) * unit_y)
+        .post_scale(width / (box_w * unit_x), height / (box_h * unit_y));
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    Some(SpriteImage {
+        name: name.to_string(),
+        pixmap,
+        pivot_offset: (0.0, 0.0),
+    })
+}
+
+/// Rasterise one themed SVG at the given pixel ratio.
+///
+/// The image is padded so the pivot point lands at its centre, which lets
+/// MapLibre's default `icon-anchor: center` place the symbol on its pivot.
+fn rasterise_symbol(name: &str, svg: &str, pixel_ratio: u32) -> Option<SpriteImage> {
+    let tree = match usvg::Tree::from_str(svg, &usvg::Options::default()) {
+        Ok(tree) => tree,
+        Err(e) => {
+            warn!("Failed to parse SVG for {}: {}", name, e);
+            return None;
+        }
+    };
+
+    let ratio = pixel_ratio as f32;
+    let width = tree.size().width() * ratio;
+    let height = tree.size().height() * ratio;
+    let (fx, fy) = pivot_fraction(svg).unwrap_or((0.5, 0.5));
+    let (pivot_x, pivot_y) = (fx * width, fy * height);
+
+    // Grow each axis to twice the larger distance from the pivot to an edge
+    let half_w = pivot_x.max(width - pivot_x).max(0.5).ceil();
+    let half_h = pivot_y.max(height - pivot_y).max(0.5).ceil();
+    let mut pixmap = Pixmap::new((half_w * 2.0) as u32, (half_h * 2.0) as u32)?;
+
+    let transform =
+        Transform::from_scale(ratio, ratio).post_translate(half_w - pivot_x, half_h - pivot_y);
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    Some(SpriteImage {
+        name: name.to_string(),
+        pixmap,
+        pivot_offset: (pivot_x - width / 2.0, pivot_y - height / 2.0),
+    })
+}
+
+/// Pack images into a single sheet using shelf packing (tallest first) and
+/// build the matching Mapbox sprite manifest.
+fn pack_sprite_sheet(images: &[SpriteImage], pixel_ratio: u32) -> (Pixmap, Map<String, Value>) {
+    let mut order: Vec<&SpriteImage> = images.iter().collect();
+    order.sort_by(|a, b| {
+        b.pixmap
+            .height()
+            .cmp(&a.pixmap.height())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    // Aim for a roughly square sheet, but never narrower than the widest image
+    let total_area: u32 = order
+        .iter()
+        .map(|img| (img.pixmap.width() + SHEET_PADDING) * (img.pixmap.height() + SHEET_PADDING))
+        .sum();
+    let widest = order
+        .iter()
+        .map(|img| img.pixmap.width())
+        .max()
+        .unwrap_or(1);
+    let sheet_width = widest.max((total_area as f64).sqrt().ceil() as u32);
+
+    let mut positions = Vec::with_capacity(order.len());
+    let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+    for img in &order {
+        if x > 0 && x + img.pixmap.width() > sheet_width {
+            x = 0;
+            y += shelf_height + SHEET_PADDING;
+            shelf_height = 0;
+        }
+        positions.push((x, y));
+        x += img.pixmap.width() + SHEET_PADDING;
+        shelf_height = shelf_height.max(img.pixmap.height());
+    }
+    let sheet_height = (y + shelf_height).max(1);
+
+    let mut sheet =
+        Pixmap::new(sheet_width, sheet_height).expect("Failed to allocate sprite sheet");
+    let mut manifest = Map::new();
+
+    for (img, &(x, y)) in order.iter().zip(&positions) {
+        sheet.draw_pixmap(
+            x as i32,
+            y as i32,
+            img.pixmap.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+        manifest.insert(
+            img.name.clone(),
+            json!({
+                "x": x,
+                "y": y,
+                "width": img.pixmap.width(),
+                "height": img.pixmap.height(),
+                "pixelRatio": pixel_ratio,
+                "pivotOffset": [img.pivot_offset.0, img.pivot_offset.1],
+            }),
+        );
+    }
+
+    (sheet, manifest)
+}
+
+/// Rasterise and pack themed SVGs into `{theme}.png`/`{theme}.json` (and the
+/// `@2x` variants) directly under `output_dir`.
+fn write_sprite_sheets(themed_svgs: &[(String, String)], theme: &str, output_dir: &Path) {
+    for &pixel_ratio in PIXEL_RATIOS {
+        let images: Vec<SpriteImage> = themed_svgs
+            .iter()
+            .filter_map(|(name, svg)| {
+                if is_pattern(name) {
+                    rasterise_pattern(name, svg, pixel_ratio)
+                } else {
+                    rasterise_symbol(name, svg, pixel_ratio)
+                }
+            })
+            .collect();
+
+        let (sheet, manifest) = pack_sprite_sheet(&images, pixel_ratio);
+
+        let suffix = if pixel_ratio == 1 {
+            String::new()
+        } else {
+            format!("@{}x", pixel_ratio)
+        };
+        let png_path = output_dir.join(format!("{theme}{suffix}.png"));
+        let json_path = output_dir.join(format!("{theme}{suffix}.json"));
+
+        sheet
+            .save_png(&png_path)
+            .expect("Failed to write sprite sheet PNG");
+        let manifest_json =
+            serde_json::to_string_pretty(&manifest).expect("Failed to serialize sprite manifest");
+        fs::write(&json_path, manifest_json).expect("Failed to write sprite manifest");
+
+        info!(
+            "Packed {} sprites into {:?} ({}x{})",
+            images.len(),
+            png_path,
+            sheet.width(),
+            sheet.height()
+        );
+    }
+}
+
+/// Generate themed sprite directories with CSS inlined into each SVG, plus
+/// packed PNG sprite sheets and manifests for each theme.
 pub fn generate_themed_sprites(svg_source_dir: &Path, output_dir: &Path) {
+    let mut svg_paths: Vec<_> = fs::read_dir(svg_source_dir)
+        .expect("Failed to read SVG source directory")
+        .map(|entry| entry.expect("Failed to read directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "svg"))
+        .collect();
+    svg_paths.sort();
+
     for &theme in THEME_NAMES {
         let css = generate_css(theme);
+        // The NODTA page background suits SVG previews but must not end up in the sheets
+        let sheet_css = format!("{css}svg {{background-color: transparent}}\n");
         let theme_dir = output_dir.join(theme);
         fs::create_dir_all(&theme_dir).expect("Failed to create theme output directory");
 
-        let mut count = 0;
-        for entry in fs::read_dir(svg_source_dir).expect("Failed to read SVG source directory") {
-            let entry = entry.expect("Failed to read directory entry");
-            let path = entry.path();
-
-            if path.extension().is_some_and(|ext| ext == "svg") {
-                let svg_content = fs::read_to_string(&path).expect("Failed to read SVG file");
-                let themed_svg = svg_content.replace(
-                    "</svg>",
-                    &format!("<defs><style>{css}</style></defs></svg>"),
-                );
-
-                let dest = theme_dir.join(entry.file_name());
-                fs::write(&dest, themed_svg).expect("Failed to write themed SVG");
-                count += 1;
-            }
+        let mut themed_svgs: Vec<(String, String)> = Vec::new();
+        for path in &svg_paths {
+            let svg_content = fs::read_to_string(path).expect("Failed to read SVG file");
+            let themed_svg = svg_content.replace(
+                "</svg>",
+                &format!("<defs><style>{css}</style></defs></svg>"),
+            );
+            let sheet_svg = svg_content.replace(
+                "</svg>",
+                &format!("<defs><style>{sheet_css}</style></defs></svg>"),
+            );
+
+            let file_name = path.file_name().expect("SVG path has no file name");
+            fs::write(theme_dir.join(file_name), &themed_svg).expect("Failed to write themed SVG");
+
+            let name = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_string();
+            themed_svgs.push((name, sheet_svg));
         }
 
-        info!("Generated {} themed SVGs for '{}'", count, theme);
+        info!(
+            "Generated {} themed SVGs for '{}'",
+            themed_svgs.len(),
+            theme
+        );
+
+        write_sprite_sheets(&themed_svgs, theme, output_dir);
     }
 }
@@ -1,23 +1,168 @@
 use serde_json::{Map, Value, json};
 use std::sync::LazyLock;
 
-use crate::feature::LayerDef;
+use crate::feature::{DisplayCategory, LayerDef};
 
 /// Mapbox GL layer type
 #[derive(Clone, Copy)]
 pub enum StyleLayerType {
     Fill,
+    /// Area pattern fill from the AP property (S-52 pattern symbols)
+    Pattern,
     Line,
     Icon,
     Text,
 }
 
+/// S-52 DASH line style (3.6 mm dash, 1.8 mm gap), in millimetres
+pub const DASH: &[f64] = &[3.6, 1.8];
+
+/// S-52 DOTT line style (0.6 mm dot, 1.2 mm gap), in millimetres
+pub const DOTT: &[f64] = &[0.6, 1.2];
+
+/// Pixels per millimetre at the 96 dpi reference resolution of the sprites
+const PX_PER_MM: f64 = 96.0 / 25.4;
+
+/// One S-52 line width unit, in millimetres
+const S52_WIDTH_UNIT_MM: f64 = 0.32;
+
+/// S-52 complex line style, selected per feature by the LS token.
+///
+/// The line is drawn with `line-dasharray`, and the optional symbol is
+/// repeated along it by a symbol layer with `symbol-placement: line`. The
+/// colour still comes from the feature's LC token.
+pub struct LineStyleDef {
+    /// LS token, e.g. "RESARE51"
+    pub name: &'static str,
+    /// Line width in S-52 units (0.32 mm)
+    pub width: f64,
+    /// Dash and gap lengths in millimetres; None for a solid line
+    pub dash_mm: Option<&'static [f64]>,
+    /// Sprite repeated along the line
+    pub symbol: Option<&'static str>,
+    /// Distance between repeated symbols in millimetres
+    pub symbol_spacing_mm: f64,
+    /// Draw the line itself; false when the repeated symbol is the line
+    pub draw_line: bool,
+}
+
+impl LineStyleDef {
+    pub const fn new(name: &'static str, width: f64) -> Self {
+        Self {
+            name,
+            width,
+            dash_mm: None,
+            symbol: None,
+            symbol_spacing_mm: 0.0,
+            draw_line: true,
+        }
+    }
+
+    /// Dash the line (dash and gap lengths in millimetres)
+    pub const fn with_dash(mut self, dash_mm: &'static [f64]) -> Self {
+        self.dash_mm = Some(dash_mm);
+        self
+    }
+
+    /// Repeat a sprite along the line every `spacing_mm` millimetres
+    pub const fn with_symbol(mut self, symbol: &'static str, spacing_mm: f64) -> Self {
+        self.symbol = Some(symbol);
+        self.symbol_spacing_mm = spacing_mm;
+        self
+    }
+
+    /// Draw only the repeated symbol, e.g. the continuous wave of a
+    /// submarine cable, with no line under it
+    pub const fn symbol_only(mut self) -> Self {
+        self.draw_line = false;
+        self
+    }
+
+    /// Dash pattern in line widths, as `line-dasharray` expects
+    fn dasharray(&self) -> Option<Vec<f64>> {
+        self.dash_mm
+            .map(|dash| dash_in_line_widths(dash, self.width * S52_WIDTH_UNIT_MM))
+    }
+}
+
+/// Convert dash and gap lengths in millimetres to multiples of a line
+/// `width_mm` wide, the unit of `line-dasharray`
+fn dash_in_line_widths(dash_mm: &[f64], width_mm: f64) -> Vec<f64> {
+    dash_mm.iter().map(|mm| mm / width_mm).collect()
+}
+
+/// S-52 complex line styles. The along-line sprites are the `...L` SVGs in
+/// `sprites/svg`, drawn with the pivot on the line and x along it.
+pub const LINE_STYLES: &[LineStyleDef] = &[
+    LineStyleDef::new("ACHARE51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("ACHARE51L", 15.0),
+    LineStyleDef::new("ACHRES51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("ACHRES51L", 15.0),
+    LineStyleDef::new("CBLARE51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("CBLARE51L", 10.0),
+    LineStyleDef::new("CBLSUB06", 1.0)
+        .with_symbol("CBLSUB06L", 4.0)
+        .symbol_only(),
+    LineStyleDef::new("CTYARE51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("CTYARE51L", 15.0),
+    LineStyleDef::new("ENTRES51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("ENTRES51L", 10.0),
+    LineStyleDef::new("FSHRES51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("FSHRES51L", 15.0),
+    LineStyleDef::new("PIPSOL05", 2.0)
+        .with_dash(DASH)
+        .with_symbol("PIPSOL05L", 10.0),
+    LineStyleDef::new("PIPSOL06", 2.0)
+        .with_dash(DASH)
+        .with_symbol("PIPSOL06L", 10.0),
+    LineStyleDef::new("RESARE51", 2.0)
+        .with_dash(DASH)
+        .with_symbol("RESARE51L", 10.0),
+];
+
+fn line_style(name: &str) -> &'static LineStyleDef {
+    LINE_STYLES
+        .iter()
+        .find(|ls| ls.name == name)
+        .unwrap_or_else(|| panic!("Unknown line style '{}'", name))
+}
+
+/// Line width for S-52 width units, in pixels, scaled by zoom: half width
+/// at z6, nominal at z12 and one and a half times at z18.
+fn s52_line_width(units: f64) -> Value {
+    let px = units * S52_WIDTH_UNIT_MM * PX_PER_MM;
+    json!([
+        "interpolate",
+        ["linear"],
+        ["zoom"],
+        6,
+        round_px(px * 0.5),
+        12,
+        round_px(px),
+        18,
+        round_px(px * 1.5)
+    ])
+}
+
+/// Round a pixel size to 0.01 px to keep the style JSON readable
+fn round_px(px: f64) -> f64 {
+    (px * 100.0).round() / 100.0
+}
+
 /// Declarative description of one Mapbox GL style layer for a feature type.
 pub struct StyleLayerDef {
     pub id_suffix: &'static str,
     pub layer_type: StyleLayerType,
     pub colors: &'static [&'static str],
     pub line_width: Option<f64>,
+    /// Line dash pattern, dash and gap lengths in millimetres (e.g. S-52 DASH)
+    pub line_dash: Option<&'static [f64]>,
     /// Property name to use for text-field (e.g., "depth")
     pub text_field: Option<&'static str>,
     /// Text size in pixels
@@ -32,6 +177,19 @@ pub struct StyleLayerDef {
     pub text_offset: Option<(f64, f64)>,
     /// Use AC (area color) token for text color instead of fixed black
     pub area_color_for_text: bool,
+    /// Rotate icons by the ROT property (ORIENT, degrees from true north)
+    pub icon_rotate: bool,
+    /// Symbol placement for icon/text layers ("point" by default, or "line")
+    pub symbol_placement: Option<&'static str>,
+    /// Mapbox GL filter expression as a JSON string, for splitting one
+    /// source layer into differently styled style layers
+    pub filter: Option<&'static str>,
+    /// S-52 complex line styles (LS tokens) this line layer can draw.
+    /// Features with one of these LS values get that style; the rest keep
+    /// the layer's own width and dash.
+    pub line_styles: &'static [&'static str],
+    /// S-52 display priority (0-9), overriding the LayerDef's
+    pub display_priority: Option<i32>,
 }
 
 impl StyleLayerDef {
@@ -42,6 +200,7 @@ impl StyleLayerDef {
             layer_type,
             colors: &[],
             line_width: None,
+            line_dash: None,
             text_field: None,
             text_size: None,
             text_halo_width: None,
@@ -49,6 +208,11 @@ impl StyleLayerDef {
             text_anchor: None,
             text_offset: None,
             area_color_for_text: false,
+            icon_rotate: false,
+            symbol_placement: None,
+            filter: None,
+            line_styles: &[],
+            display_priority: None,
         }
     }
 
@@ -64,6 +228,12 @@ impl StyleLayerDef {
         self
     }
 
+    /// Set the line dash pattern (dash and gap lengths in millimetres)
+    pub const fn with_line_dash(mut self, dash: &'static [f64]) -> Self {
+        self.line_dash = Some(dash);
+        self
+    }
+
     /// Set text field and optional size
     pub const fn with_text(mut self, field: &'static str, size: f64) -> Self {
         self.text_field = Some(field);
@@ -100,10 +270,63 @@ impl StyleLayerDef {
         self.area_color_for_text = true;
         self
     }
+
+    /// Rotate icons by the ROT property, aligned to the map so they keep
+    /// pointing along their true bearing as the map rotates
+    pub const fn with_icon_rotation(mut self) -> Self {
+        self.icon_rotate = true;
+        self
+    }
+
+    /// Set symbol placement (e.g. "line" to run labels along cables)
+    pub const fn with_symbol_placement(mut self, placement: &'static str) -> Self {
+        self.symbol_placement = Some(placement);
+        self
+    }
+
+    /// Set a filter expression (JSON), e.g. `r#"["==", ["get", "condtn"], 1]"#`
+    pub const fn with_filter(mut self, filter: &'static str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Set the display priority (0-9) of this style layer
+    pub const fn with_display_priority(mut self, priority: i32) -> Self {
+        self.display_priority = Some(priority);
+        self
+    }
+
+    /// Draw features whose LS token is one of `names` with that S-52 line style
+    pub const fn with_line_styles(mut self, names: &'static [&'static str]) -> Self {
+        self.line_styles = names;
+        self
+    }
 }
 
 pub const THEME_NAMES: &[&str] = &["day", "dusk", "night"];
 
+/// Display category selections a client can switch between, from least to
+/// most content. "base" keeps only what S-52 never lets you remove.
+pub const DISPLAY_MODES: &[(&str, &[DisplayCategory])] = &[
+    ("base", &[DisplayCategory::Base]),
+    (
+        "standard",
+        &[DisplayCategory::Base, DisplayCategory::Standard],
+    ),
+    ("all", DisplayCategory::ALL),
+];
+
+/// Style toggle for the enc_catalog cell outlines and labels
+pub const CHART_BOUNDARIES_TOGGLE: &str = "chart_boundaries";
+
+fn display_mode_categories(mode: &str) -> &'static [DisplayCategory] {
+    DISPLAY_MODES
+        .iter()
+        .find(|(name, _)| *name == mode)
+        .map(|(_, categories)| *categories)
+        .unwrap_or_else(|| panic!("Unknown display category '{}'", mode))
+}
+
 static COLORS_JSON: LazyLock<Value> =
     LazyLock::new(|| serde_json::from_str(include_str!("../colors.json")).unwrap());
 
@@ -125,14 +348,205 @@ fn build_case_expression(prop: &str, tokens: &[&str], colors: &Map<String, Value
     Value::Array(expr)
 }
 
+/// The layer's filter, if any, combined with `condition`
+fn and_filter(layer: &Value, condition: Value) -> Value {
+    match layer.get("filter") {
+        Some(filter) => json!(["all", condition, filter]),
+        None => condition,
+    }
+}
+
+/// Filter condition keeping features whose DISPCAT is one of `categories`.
+/// Features without DISPCAT (imported before the column existed) count as
+/// the layer's default category.
+fn display_condition(default: DisplayCategory, categories: &[DisplayCategory]) -> Value {
+    let names: Vec<&str> = categories.iter().map(|c| c.as_str()).collect();
+    json!([
+        "in",
+        ["coalesce", ["get", "DISPCAT"], default.as_str()],
+        ["literal", names]
+    ])
+}
+
+/// Restrict a style layer to the selected display categories. The layer's
+/// own filter and default category are kept in its metadata, so a client can
+/// rebuild the filter when the user switches Base/Standard/All.
+fn apply_display_filter(
+    layer: &mut Value,
+    default: DisplayCategory,
+    categories: &[DisplayCategory],
+) {
+    layer["metadata"] = json!({
+        "openenc:display_category": default.as_str(),
+        "openenc:filter": layer.get("filter").cloned().unwrap_or(Value::Null),
+    });
+    layer["filter"] = and_filter(layer, display_condition(default, categories));
+}
+
+/// Draw order key for a style layer: text above everything else, then by
+/// display priority, then areas, lines and symbols within a priority.
+fn draw_order(layer: &Value, priority: i32) -> (bool, i32, u8) {
+    let is_text = layer["layout"].get("text-field").is_some();
+    let geometry_rank = match layer["type"].as_str() {
+        Some("fill") => 0,
+        Some("line") => 1,
+        _ => 2,
+    };
+    (is_text, priority, geometry_rank)
+}
+
+/// `symbol-sort-key` placing higher-priority features first when symbols
+/// collide. The feature's own PRIO wins over the style layer's priority.
+fn symbol_sort_key(priority: i32) -> Value {
+    json!(["-", 9, ["coalesce", ["get", "PRIO"], priority]])
+}
+
+/// Style layers for the S-52 complex line styles of a line layer: for each
+/// LS token, a copy of the line with the style's width and dashes, plus a
+/// symbol layer repeating its sprite along the line.
+fn complex_line_layers(base: &Value, names: &[&str]) -> Vec<Value> {
+    let mut layers = Vec::new();
+
+    for &name in names {
+        let ls = line_style(name);
+        let filter = and_filter(base, json!(["==", ["get", "LS"], name]));
+        let id = format!(
+            "{}_{}",
+            base["id"].as_str().unwrap_or_default(),
+            name.to_lowercase()
+        );
+
+        let mut line = base.clone();
+        line["id"] = json!(id);
+        line["filter"] = filter.clone();
+        line["paint"]["line-width"] = s52_line_width(ls.width);
+        match ls.dasharray() {
+            Some(dash) => line["paint"]["line-dasharray"] = json!(dash),
+            None => {
+                if let Some(paint) = line["paint"].as_object_mut() {
+                    paint.remove("line-dasharray");
+                }
+            }
+        }
+        if ls.draw_line {
+            layers.push(line);
+        }
+
+        if let Some(symbol) = ls.symbol {
+            layers.push(json!({
+                "id": format!("{}_symbol", id),
+                "type": "symbol",
+                "source": base["source"],
+                "source-layer": base["source-layer"],
+                "filter": filter,
+                "layout": {
+                    "symbol-placement": "line",
+                    "symbol-spacing": round_px(ls.symbol_spacing_mm * PX_PER_MM),
+                    "icon-image": symbol,
+                    "icon-rotation-alignment": "map",
+                    "icon-allow-overlap": true,
+                    "icon-ignore-placement": true,
+                },
+            }));
+        }
+    }
+
+    layers
+}
+
+/// Draw the overscale pattern over area fills up to restricted areas, but
+/// under all line work and symbols
+const OVERSCALE_DISPLAY_PRIORITY: i32 = 3;
+
+/// Fill the `overscale` source layer with its AP pattern (OVERSC01P). The
+/// indication is a safety warning, so it is not tied to a display category.
+fn overscale_layer() -> Value {
+    json!({
+        "id": "overscale_pattern",
+        "type": "fill",
+        "source": "enc",
+        "source-layer": "overscale",
+        "filter": ["has", "AP"],
+        "paint": {
+            "fill-pattern": ["get", "AP"],
+        },
+    })
+}
+
+/// Style layers outlining each cell's coverage from the `enc_catalog` source
+/// layer, labelled with the cell name, compilation scale, edition/update and
+/// usage band. Drawn above the chart and hidden unless `visible`; a client
+/// flips them through the chart boundaries toggle.
+fn chart_boundary_layers(colors: &Map<String, Value>, visible: bool) -> Vec<Value> {
+    let visibility = if visible { "visible" } else { "none" };
+    let color = colors
+        .get("CHMGD")
+        .cloned()
+        .unwrap_or_else(|| json!("#c045d1"));
+    let metadata = json!({ "openenc:toggle": CHART_BOUNDARIES_TOGGLE });
+
+    vec![
+        json!({
+            "id": "enc_catalog_boundary",
+            "type": "line",
+            "source": "enc",
+            "source-layer": "enc_catalog",
+            "metadata": metadata,
+            "layout": {
+                "visibility": visibility,
+            },
+            "paint": {
+                "line-color": color,
+                "line-width": 1.5,
+                "line-dasharray": [4, 2],
+            },
+        }),
+        json!({
+            "id": "enc_catalog_label",
+            "type": "symbol",
+            "source": "enc",
+            "source-layer": "enc_catalog",
+            "metadata": metadata,
+            "layout": {
+                "visibility": visibility,
+                "symbol-placement": "line",
+                "text-field": [
+                    "concat",
+                    ["get", "enc_name"],
+                    " 1:",
+                    ["to-string", ["get", "compilation_scale"]],
+                    " ed ",
+                    ["to-string", ["coalesce", ["get", "edition"], "-"]],
+                    ".",
+                    ["to-string", ["coalesce", ["get", "update_number"], 0]],
+                    " band ",
+                    ["to-string", ["coalesce", ["get", "usage_band"], "-"]],
+                ],
+                "text-font": ["Roboto Bold"],
+                "text-size": 11,
+            },
+            "paint": {
+                "text-color": color,
+                "text-halo-color": "#ffffff",
+                "text-halo-width": 1.5,
+            },
+        }),
+    ]
+}
+
 pub fn generate_style_json(
     layers: &[&LayerDef],
     theme_name: &str,
     tile_source_url: &str,
+    sprite_base_url: &str,
+    glyphs_url: &str,
+    display_mode: &str,
+    chart_boundaries: bool,
 ) -> String {
     let colors = color_map_for_theme(theme_name);
+    let categories = display_mode_categories(display_mode);
 
-    let mut style_layers: Vec<Value> = Vec::new();
+    let mut style_layers: Vec<((bool, i32, u8), Value)> = Vec::new();
 
     for layer_def in layers {
         for sld in layer_def.style_layers {
@@ -143,6 +557,16 @@ pub fn generate_style_json(
                 "source-layer": layer_def.table,
             });
 
+            if let Some(filter) = sld.filter {
+                layer["filter"] = serde_json::from_str(filter)
+                    .unwrap_or_else(|e| panic!("Invalid filter for style layer '{}': {}", id, e));
+            }
+
+            // Pattern layers only draw features whose style_fn chose a pattern
+            if let StyleLayerType::Pattern = sld.layer_type {
+                layer["filter"] = and_filter(&layer, json!(["has", "AP"]));
+            }
+
             match sld.layer_type {
                 StyleLayerType::Fill => {
                     layer["type"] = json!("fill");
@@ -150,6 +574,12 @@ pub fn generate_style_json(
                         "fill-color": build_case_expression("AC", sld.colors, colors),
                     });
                 }
+                StyleLayerType::Pattern => {
+                    layer["type"] = json!("fill");
+                    layer["paint"] = json!({
+                        "fill-pattern": ["get", "AP"],
+                    });
+                }
                 StyleLayerType::Line => {
                     layer["type"] = json!("line");
                     let mut paint = json!({
@@ -158,13 +588,26 @@ pub fn generate_style_json(
                     if let Some(w) = sld.line_width {
                         paint["line-width"] = json!(w);
                     }
+                    if let Some(dash) = sld.line_dash {
+                        // line-width is in pixels, 1 when not set
+                        let width_mm = sld.line_width.unwrap_or(1.0) / PX_PER_MM;
+                        paint["line-dasharray"] = json!(dash_in_line_widths(dash, width_mm));
+                    }
                     layer["paint"] = paint;
                 }
                 StyleLayerType::Icon => {
                     layer["type"] = json!("symbol");
-                    layer["layout"] = json!({
+                    let mut layout = json!({
                         "icon-image": ["get", "SY"],
                     });
+                    if sld.icon_rotate {
+                        layout["icon-rotate"] = json!(["coalesce", ["get", "ROT"], 0]);
+                        layout["icon-rotation-alignment"] = json!("map");
+                    }
+                    if let Some(placement) = sld.symbol_placement {
+                        layout["symbol-placement"] = json!(placement);
+                    }
+                    layer["layout"] = layout;
                 }
                 StyleLayerType::Text => {
                     layer["type"] = json!("symbol");
@@ -186,6 +629,9 @@ pub fn generate_style_json(
                         if let Some((x, y)) = sld.text_offset {
                             layout["text-offset"] = json!([x, y]);
                         }
+                        if let Some(placement) = sld.symbol_placement {
+                            layout["symbol-placement"] = json!(placement);
+                        }
 
                         // Add text paint properties
                         let text_color = if sld.area_color_for_text && !sld.colors.is_empty() {
@@ -212,10 +658,46 @@ pub fn generate_style_json(
                 }
             }
 
-            style_layers.push(layer);
+            // Complex line styles get their own layers; the base layer keeps
+            // the features whose LS is not one of them
+            let styled = complex_line_layers(&layer, sld.line_styles);
+            if !sld.line_styles.is_empty() {
+                let unstyled = json!([
+                    "!",
+                    [
+                        "in",
+                        ["coalesce", ["get", "LS"], ""],
+                        ["literal", sld.line_styles]
+                    ]
+                ]);
+                layer["filter"] = and_filter(&layer, unstyled);
+            }
+
+            let priority = sld.display_priority.unwrap_or(layer_def.display_priority);
+            for mut layer in std::iter::once(layer).chain(styled) {
+                apply_display_filter(&mut layer, layer_def.display_category, categories);
+                if layer["type"] == "symbol" {
+                    layer["layout"]["symbol-sort-key"] = symbol_sort_key(priority);
+                }
+                style_layers.push((draw_order(&layer, priority), layer));
+            }
         }
     }
 
+    let overscale = overscale_layer();
+    style_layers.push((
+        draw_order(&overscale, OVERSCALE_DISPLAY_PRIORITY),
+        overscale,
+    ));
+
+    // Stable sort, so layers with equal keys keep their all_layers() order
+    style_layers.sort_by_key(|(order, _)| *order);
+    let mut style_layers: Vec<Value> = style_layers.into_iter().map(|(_, layer)| layer).collect();
+
+    let boundary_layers = chart_boundary_layers(colors, chart_boundaries);
+    let boundary_ids: Vec<Value> = boundary_layers.iter().map(|l| l["id"].clone()).collect();
+    style_layers.extend(boundary_layers);
+
     let mut sources = serde_json::Map::new();
     sources.insert(
         "enc".to_string(),
@@ -230,12 +712,32 @@ pub fn generate_style_json(
     let style = json!({
         "version": 8,
         "name": format!("openenc-{}", theme_name),
-        "sprite": format!("{}/sprite/{}", tile_source_url, theme_name),
-        "glyphs": format!("{}/font/{{fontstack}}/{{range}}", tile_source_url),
+        "sprite": format!("{}/{}", sprite_base_url, theme_name),
+        "glyphs": glyphs_url,
         "center": [-122.3321, 47.6062],
         "zoom": 8,
         "sources": sources,
         "layers": style_layers,
+        "metadata": {
+            "openenc:display": {
+                "mode": display_mode,
+                "modes": DISPLAY_MODES
+                    .iter()
+                    .map(|(name, categories)| {
+                        let names: Vec<&str> = categories.iter().map(|c| c.as_str()).collect();
+                        (name.to_string(), json!(names))
+                    })
+                    .collect::<Map<String, Value>>(),
+                "category_property": "DISPCAT",
+                "viewing_group_property": "VIEWGRP",
+            },
+            "openenc:toggles": {
+                CHART_BOUNDARIES_TOGGLE: {
+                    "enabled": chart_boundaries,
+                    "layers": boundary_ids,
+                },
+            },
+        },
     });
 
     serde_json::to_string_pretty(&style).expect("Failed to serialize style JSON")
@@ -1,5 +1,5 @@
 use gdal::vector::FieldValue;
-use serde_json::{Value, json};
+use serde_json::{Map, Value, json};
 use std::path::Path;
 
 /// Convert GDAL field value to JSON value
@@ -18,6 +18,101 @@ pub fn field_value_to_json(field_value: &FieldValue) -> Option<Value> {
     }
 }
 
+/// Read an S-57 list attribute (e.g. RESTRN, CATREA) as integers.
+///
+/// Accepts integer lists, string lists, single values and comma-separated
+/// strings ("1,7"), the forms GDAL produces for S-57 list fields.
+pub fn int_list(value: &Value) -> Vec<i64> {
+    let parse = |v: &Value| -> Vec<i64> {
+        if let Some(i) = v.as_i64() {
+            vec![i]
+        } else if let Some(s) = v.as_str() {
+            s.split(',')
+                .filter_map(|part| part.trim().parse::<i64>().ok())
+                .collect()
+        } else {
+            Vec::new()
+        }
+    };
+
+    match value.as_array() {
+        Some(arr) => arr.iter().flat_map(parse).collect(),
+        None => parse(value),
+    }
+}
+
+/// Split an S-57 comma-separated value string (e.g. T_TSVL "1.2,,1.4") into
+/// fields. Empty fields are kept, so every value stays at its position.
+fn csv_fields(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(arr) => arr.iter().flat_map(csv_fields).collect(),
+        Value::String(s) if s.trim().is_empty() => Vec::new(),
+        Value::String(s) => s.split(',').map(|part| part.trim().to_string()).collect(),
+        Value::Number(n) => vec![n.to_string()],
+        _ => Vec::new(),
+    }
+}
+
+/// A time series value, or null when the field is empty or not a number
+fn csv_number(field: Option<&String>) -> Value {
+    field
+        .and_then(|f| f.parse::<f64>().ok())
+        .map_or(Value::Null, |n| json!(n))
+}
+
+/// Direction/rate pairs by position, as `{"direction": .., "rate": ..}`
+/// objects. A missing value is null and does not shift later pairs.
+fn direction_rate_pairs(fields: &[String]) -> impl Iterator<Item = Map<String, Value>> + '_ {
+    fields.chunks(2).map(|pair| {
+        let mut entry = Map::new();
+        entry.insert("direction".into(), csv_number(pair.first()));
+        entry.insert("rate".into(), csv_number(pair.get(1)));
+        entry
+    })
+}
+
+/// Parse TS_TSV (tidal stream time series), a sequence of direction/rate pairs,
+/// as `[{"direction": 45.0, "rate": 1.5}, ...]`.
+pub fn stream_series(value: &Value) -> Option<Value> {
+    let fields = csv_fields(value);
+    let pairs: Vec<Value> = direction_rate_pairs(&fields).map(Value::Object).collect();
+    (!pairs.is_empty()).then_some(Value::Array(pairs))
+}
+
+/// Parse T_TSVL (tide height time series) as `[1.2, null, 1.4, ...]`.
+pub fn height_series(value: &Value) -> Option<Value> {
+    let heights: Vec<Value> = csv_fields(value)
+        .iter()
+        .map(|f| csv_number(Some(f)))
+        .collect();
+    (!heights.is_empty()).then_some(Value::Array(heights))
+}
+
+/// Parse TS_TSP (tidal stream panel values): the reference station, the
+/// reference tide (HW or LW), then direction/rate pairs for each hour from
+/// 6 hours before to 6 hours after it.
+pub fn stream_panel(value: &Value) -> Option<Value> {
+    let fields = csv_fields(value);
+    let (station, tide, rest) = match fields.as_slice() {
+        [station, tide, rest @ ..] => (station, tide, rest),
+        _ => return None,
+    };
+
+    let hours: Vec<Value> = direction_rate_pairs(rest)
+        .zip(-6..)
+        .map(|(mut entry, hour)| {
+            entry.insert("hour".into(), json!(hour));
+            Value::Object(entry)
+        })
+        .collect();
+
+    Some(json!({
+        "reference_station": station,
+        "reference_tide": tide,
+        "hours": hours,
+    }))
+}
+
 /// Extract ENC cell name from directory or file path
 pub fn enc_name_from_path(s57_path: &Path) -> String {
     s57_path
@@ -26,3 +121,14 @@ pub fn enc_name_from_path(s57_path: &Path) -> String {
         .map(|name| name.split('.').next().unwrap_or(name).to_string())
         .unwrap_or_else(|| "unknown".to_string())
 }
+
+/// Navigational purpose (usage band 1-6, overview to berthing) encoded as the
+/// third character of an S-57 cell name, e.g. `5` in `US5WA22M`.
+pub fn usage_band_from_enc_name(enc_name: &str) -> Option<i32> {
+    enc_name
+        .chars()
+        .nth(2)
+        .and_then(|c| c.to_digit(10))
+        .map(|band| band as i32)
+        .filter(|band| (1..=6).contains(band))
+}